@@ -2,9 +2,24 @@ use crate::prelude::*;
 
 #[derive(Clone, Copy, Component)]
 pub struct Client;
+
+/// Marks a client that is not on any viewed tag and was moved off-screen.
+#[derive(Clone, Copy, Debug, Component)]
+pub struct Hidden;
+
 wrapper!(ClientName(String));
 wrapper!(ClientFrame(Window));
 wrapper!(ClientWindow(Window));
+wrapper!(ClientWeight(f32));
+
+/// The weight new clients are using.
+pub const DEFAULT_CLIENT_WEIGHT: f32 = 1.0;
+
+/// The smallest weight a client can have.
+pub const MIN_CLIENT_WEIGHT: f32 = 0.25;
+
+/// The largest weight a client can have.
+pub const MAX_CLIENT_WEIGHT: f32 = 4.0;
 
 /// Finds the monitor this client should primarily be located on from the given [`monitor_geoms`]
 /// and returns the index of the geometry in the given [`monitor_geoms`].
@@ -29,6 +44,7 @@ pub fn find_monitor(client_geometry: Geometry, monitor_geoms: Vec<Geometry>) ->
 
 /// X11 size hints of a client.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Getters, Default, Component)]
+#[constructor(named(new), fields(base, step, max, min, aspect_ratio))]
 pub struct SizeHints {
     /// The base size.
     base: SizeDimensionHint,
@@ -46,6 +62,7 @@ pub struct SizeHints {
 
 /// A dimension size hint.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Getters, Default)]
+#[constructor(named(new), fields(width, height))]
 pub struct SizeDimensionHint {
     /// The width hint.
     width: u32,
@@ -56,6 +73,7 @@ pub struct SizeDimensionHint {
 
 /// A size constraint size hint.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Getters, Default)]
+#[constructor(named(new), fields(min, max))]
 pub struct SizeConstraintHint {
     /// The min size.
     min: f32,
@@ -81,4 +99,32 @@ pub struct ClientState {
 
     /// Whether this client is fullscreened.
     fullscreen: bool,
+
+    /// Whether this client is visible on all tags of its monitor.
+    sticky: bool,
+
+    /// Whether this client was floating before it became sticky.
+    floating_before_sticky: bool,
+}
+
+impl ClientState {
+    /// Makes this client floating or tiled.
+    pub fn change_floating(&mut self, floating: bool) -> &mut ClientState {
+        self.floating = floating;
+        self
+    }
+
+    /// Makes this client sticky or not. Clients that become sticky are floating by default and
+    /// float again only if they did before once they are no longer sticky.
+    pub fn change_sticky(&mut self, sticky: bool) -> &mut ClientState {
+        if sticky && !self.sticky {
+            self.floating_before_sticky = self.floating;
+            self.floating = true;
+        } else if !sticky && self.sticky {
+            self.floating = self.floating_before_sticky;
+        }
+
+        self.sticky = sticky;
+        self
+    }
 }
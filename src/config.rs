@@ -31,6 +31,26 @@ pub struct MainConfig {
     /// Gap configuration.
     #[serde(default)]
     gaps: GapsConfig,
+
+    /// Scrolling layout configuration.
+    #[serde(default)]
+    scrolling: ScrollingConfig,
+
+    /// Tab bar configuration.
+    #[serde(default)]
+    tabs: TabsConfig,
+
+    /// Focus configuration.
+    #[serde(default)]
+    focus: FocusConfig,
+
+    /// Named scratchpads.
+    #[serde(default)]
+    scratchpads: Vec<ScratchpadConfig>,
+
+    /// Rules applied to matching windows when they are managed.
+    #[serde(default)]
+    rules: Vec<WindowRuleConfig>,
 }
 
 impl MainConfig {
@@ -44,9 +64,50 @@ impl MainConfig {
         }
 
         self.border.validate()?;
+        self.scrolling.validate()?;
+        self.tabs.validate()?;
+        self.validate_scratchpads()?;
+
+        for rule in &self.rules {
+            rule.validate()?;
+        }
+
+        Ok(())
+    }
+
+    /// Validates all scratchpads and the bindings toggling them.
+    fn validate_scratchpads(&self) -> anyhow::Result<()> {
+        for (idx, scratchpad) in self.scratchpads.iter().enumerate() {
+            scratchpad.validate()?;
+
+            if self.scratchpads[..idx]
+                .iter()
+                .any(|other| other.name == scratchpad.name)
+            {
+                bail!("scratchpad {} is defined more than once", scratchpad.name)
+            }
+        }
+
+        for binding in &self.bindings {
+            if let KeybindAction::ToggleScratchpad(name) = binding.action()
+                && self.scratchpad(name).is_none()
+            {
+                bail!(
+                    "binding {} toggles the unknown scratchpad {name}",
+                    binding.key()
+                )
+            }
+        }
 
         Ok(())
     }
+
+    /// Gets the scratchpad with the given [`name`].
+    pub fn scratchpad(&self, name: &str) -> Option<&ScratchpadConfig> {
+        self.scratchpads
+            .iter()
+            .find(|scratchpad| scratchpad.name == name)
+    }
 }
 
 impl FromWorld for MainConfig {
@@ -126,150 +187,200 @@ impl KeyBinding {
             }
         }
 
+        if let KeybindAction::ViewTag(tag)
+        | KeybindAction::ToggleViewTag(tag)
+        | KeybindAction::SendToTag(tag)
+        | KeybindAction::FollowToTag(tag)
+        | KeybindAction::ToggleClientTag(tag) = self.action
+            && (tag < 1 || tag > MAX_TAGS as u8)
+        {
+            bail!(
+                "tag {tag} of binding {} cannot be below one or above {MAX_TAGS}",
+                self.key
+            )
+        }
+
+        if let KeybindAction::ViewWorkspace(label) | KeybindAction::RenameWorkspace(label) =
+            &self.action
+            && label.is_empty()
+        {
+            bail!("workspace label of binding {} cannot be empty", self.key)
+        }
+
         Ok(())
     }
 }
 
 mod defaults {
-    pub fn enabled_tags() -> Vec<u8> {
-        vec![1, 2, 3, 4, 5, 6, 7, 8, 9]
+    use super::TagConfig;
+
+    pub fn tags() -> Vec<TagConfig> {
+        (1..=9)
+            .map(|tag| TagConfig {
+                label: tag.to_string(),
+                layout: None,
+                master_factor: None,
+                gaps: None,
+            })
+            .collect()
     }
-}
 
-/// Configuration element for tags.
-#[derive(Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Getters)]
-pub struct TagsConfig {
-    /// All enabled tags.
-    #[serde(default = "defaults::enabled_tags")]
-    enabled_tags: Vec<u8>,
+    pub fn layout() -> String {
+        "tile".to_string()
+    }
 
-    /// Label for tag 1.
-    label_1: Option<String>,
+    pub fn master_factor() -> u8 {
+        55
+    }
 
-    /// Label for tag 2.
-    label_2: Option<String>,
+    pub fn master_count() -> u8 {
+        1
+    }
 
-    /// Label for tag 3.
-    label_3: Option<String>,
+    pub fn column_width() -> u8 {
+        50
+    }
 
-    /// Label for tag 4.
-    label_4: Option<String>,
+    pub fn scratchpad_size() -> u8 {
+        60
+    }
 
-    /// Label for tag 5.
-    label_5: Option<String>,
+    pub fn tab_height() -> u16 {
+        18
+    }
 
-    /// Label for tag 6.
-    label_6: Option<String>,
+    pub fn font() -> String {
+        "fixed".to_string()
+    }
 
-    /// Label for tag 7.
-    label_7: Option<String>,
+    pub fn raise_on_focus() -> bool {
+        true
+    }
+}
 
-    /// Label for tag 8.
-    label_8: Option<String>,
+/// Configuration element for tags.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Getters)]
+pub struct TagsConfig {
+    /// All tags of a monitor, in order. The first definition is tag 1.
+    #[serde(default = "defaults::tags")]
+    definitions: Vec<TagConfig>,
+
+    /// The name of the layout tags use by default.
+    #[serde(default = "defaults::layout")]
+    default_layout: String,
 
-    /// Label for tag 9.
-    label_9: Option<String>,
+    /// The percentage of the size that the master area of tags is using by default.
+    #[serde(default = "defaults::master_factor")]
+    default_master_factor: u8,
 
-    /// Label for tag 10.
-    label_10: Option<String>,
+    /// The amount of clients in the master area of tags by default.
+    #[serde(default = "defaults::master_count")]
+    default_master_count: u8,
 }
 
 impl Default for TagsConfig {
     fn default() -> Self {
         Self {
-            enabled_tags: defaults::enabled_tags(),
-            label_1: None,
-            label_2: None,
-            label_3: None,
-            label_4: None,
-            label_5: None,
-            label_6: None,
-            label_7: None,
-            label_8: None,
-            label_9: None,
-            label_10: None,
+            definitions: defaults::tags(),
+            default_layout: defaults::layout(),
+            default_master_factor: defaults::master_factor(),
+            default_master_count: defaults::master_count(),
         }
     }
 }
 
 impl TagsConfig {
     fn validate(&self) -> anyhow::Result<()> {
-        if self.enabled_tags.len() > MAX_TAGS {
-            bail!("enabled_tags cannot be larger than {MAX_TAGS}")
+        if self.definitions.is_empty() {
+            bail!("there has to be at least one tag")
+        }
+
+        if self.definitions.len() > MAX_TAGS {
+            bail!("there cannot be more than {MAX_TAGS} tags")
         }
 
-        for tag in self.enabled_tags.clone() {
-            if tag > MAX_TAGS as u8 || tag < 1 {
-                bail!("tag {tag} cannot be below one or above {MAX_TAGS}")
+        for (idx, definition) in self.definitions.iter().enumerate() {
+            if definition.label.is_empty() {
+                bail!("tag {} must have a label", idx + 1)
+            }
+
+            // workspaces are looked up by their label
+            if self.definitions[..idx]
+                .iter()
+                .any(|other| other.label == definition.label)
+            {
+                bail!("tag {} must have a unique label", idx + 1)
             }
 
-            let label = match tag {
-                1 => &self.label_1,
-                2 => &self.label_2,
-                3 => &self.label_3,
-                4 => &self.label_4,
-                5 => &self.label_5,
-                6 => &self.label_6,
-                7 => &self.label_7,
-                8 => &self.label_8,
-                9 => &self.label_9,
-                10 => &self.label_10,
-                _ => unreachable!(),
-            };
-
-            if label.is_none() {
-                bail!("tag {tag} must have a label")
+            if definition
+                .master_factor
+                .is_some_and(|master_factor| !(5..=95).contains(&master_factor))
+            {
+                bail!("master factor of tag {} must be between 5 and 95", idx + 1)
             }
         }
 
+        if !(5..=95).contains(&self.default_master_factor) {
+            bail!("default_master_factor must be between 5 and 95")
+        }
+
         Ok(())
     }
 
-    /// Gets all labels of all activated tags.
-    pub fn labels(&self) -> Vec<String> {
-        let mut labels = vec![];
-
-        for tag in self.enabled_tags.clone() {
-            let label = match tag {
-                1 => &self.label_1,
-                2 => &self.label_2,
-                3 => &self.label_3,
-                4 => &self.label_4,
-                5 => &self.label_5,
-                6 => &self.label_6,
-                7 => &self.label_7,
-                8 => &self.label_8,
-                9 => &self.label_9,
-                10 => &self.label_10,
-                _ => unreachable!(),
-            };
-
-            if let Some(label) = label {
-                labels.push(label.clone());
-            }
-        }
+    /// Gets the definition of the given [`tag`].
+    pub fn definition(&self, tag: u8) -> Option<&TagConfig> {
+        self.definitions.get((tag as usize).checked_sub(1)?)
+    }
+
+    /// Gets the master factor for the given [`tag`].
+    pub fn master_factor(&self, tag: u8) -> f32 {
+        let percent = self
+            .definition(tag)
+            .and_then(|definition| definition.master_factor)
+            .unwrap_or(self.default_master_factor);
+
+        percent as f32 / 100.0
+    }
+
+    /// Gets the name of the layout for the given [`tag`].
+    pub fn layout(&self, tag: u8) -> &str {
+        self.definition(tag)
+            .and_then(|definition| definition.layout.as_deref())
+            .unwrap_or(&self.default_layout)
+    }
 
-        labels
+    /// Gets all labels of all tags.
+    pub fn labels(&self) -> Vec<String> {
+        self.definitions
+            .iter()
+            .map(|definition| definition.label.clone())
+            .collect()
     }
 
     /// Gets the label for the given [`tag`].
     pub fn label(&self, tag: u8) -> Option<String> {
-        match tag {
-            1 => self.label_1.clone(),
-            2 => self.label_2.clone(),
-            3 => self.label_3.clone(),
-            4 => self.label_4.clone(),
-            5 => self.label_5.clone(),
-            6 => self.label_6.clone(),
-            7 => self.label_7.clone(),
-            8 => self.label_8.clone(),
-            9 => self.label_9.clone(),
-            10 => self.label_10.clone(),
-            _ => unreachable!(),
-        }
+        self.definition(tag)
+            .map(|definition| definition.label.clone())
     }
 }
 
+/// Configuration of a single tag.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Getters)]
+pub struct TagConfig {
+    /// The label of the tag.
+    label: String,
+
+    /// The name of the layout of the tag. Uses the default layout if not set.
+    layout: Option<String>,
+
+    /// The percentage of the size that the master area of the tag is using. Uses the default
+    /// master factor if not set.
+    master_factor: Option<u8>,
+
+    /// The gaps of the tag. Uses the global gaps if not set.
+    gaps: Option<GapsConfig>,
+}
+
 /// A colour that can be represented by RGB, hex value or a hex string.
 #[derive(Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 #[serde(untagged)]
@@ -339,6 +450,14 @@ impl BorderConfig {
 
         Ok(())
     }
+
+    /// Gets the hex value of the border colour for the given [`scheme`].
+    pub fn color(&self, scheme: ColorScheme) -> Result<u32> {
+        match scheme {
+            ColorScheme::Selected => self.selected_color.hex_value(),
+            _ => self.inactive_color.hex_value(),
+        }
+    }
 }
 
 /// Configuration of window gaps.
@@ -377,6 +496,231 @@ pub struct GapsConfig {
     inner_top: u32,
 }
 
+/// Configuration of the scrolling layout.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Getters)]
+pub struct ScrollingConfig {
+    /// The percentage of the monitor width that new columns are using.
+    #[serde(default = "defaults::column_width")]
+    column_width: u8,
+}
+
+impl Default for ScrollingConfig {
+    fn default() -> Self {
+        Self {
+            column_width: defaults::column_width(),
+        }
+    }
+}
+
+impl ScrollingConfig {
+    /// Validates this configuration section.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        if !(5..=100).contains(&self.column_width) {
+            bail!("column_width must be between 5 and 100")
+        }
+
+        Ok(())
+    }
+}
+
+/// Configuration of the tab bars of the tabbed and stacked layouts.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Getters)]
+pub struct TabsConfig {
+    /// The height of a single tab.
+    #[serde(default = "defaults::tab_height")]
+    height: u16,
+
+    /// The name of the X font used for tab titles.
+    #[serde(default = "defaults::font")]
+    font: String,
+
+    /// The colour of tab titles.
+    #[serde(default)]
+    text_color: ConfigColor,
+}
+
+impl Default for TabsConfig {
+    fn default() -> Self {
+        Self {
+            height: defaults::tab_height(),
+            font: defaults::font(),
+            text_color: ConfigColor::default(),
+        }
+    }
+}
+
+impl TabsConfig {
+    /// Validates this configuration section.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        if self.height == 0 {
+            bail!("tab height must be above zero")
+        }
+
+        if let Err(e) = self.text_color.hex_value() {
+            bail!("invalid text_color: {e:?}")
+        }
+
+        Ok(())
+    }
+}
+
+/// How the pointer focuses clients.
+#[derive(Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum FocusPolicy {
+    /// Entering a client focuses it, moving onto the root window unfocuses it again.
+    #[serde(rename = "follow_mouse")]
+    FollowMouse,
+
+    /// Entering a client focuses it, moving onto the root window keeps the focus.
+    #[default]
+    #[serde(rename = "sloppy")]
+    Sloppy,
+
+    /// Clicking a client focuses it. The click is passed on to the client afterwards.
+    #[serde(rename = "click")]
+    Click,
+}
+
+/// Configuration of how clients get focused.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Getters)]
+pub struct FocusConfig {
+    /// How the pointer focuses clients.
+    #[serde(default)]
+    #[getter(copy)]
+    policy: FocusPolicy,
+
+    /// Whether clients focused with the pointer are raised above all other clients.
+    #[serde(default = "defaults::raise_on_focus")]
+    raise: bool,
+}
+
+impl Default for FocusConfig {
+    fn default() -> Self {
+        Self {
+            policy: FocusPolicy::default(),
+            raise: defaults::raise_on_focus(),
+        }
+    }
+}
+
+/// Configuration of a scratchpad, a floating window that is shown and hidden on demand.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Getters)]
+pub struct ScratchpadConfig {
+    /// The name used by bindings to toggle this scratchpad.
+    name: String,
+
+    /// The command that spawns the program of this scratchpad.
+    command: String,
+
+    /// The WM_CLASS instance or class name of the window of this scratchpad.
+    class: Option<String>,
+
+    /// The title of the window of this scratchpad.
+    title: Option<String>,
+
+    /// The percentage of the monitor width this scratchpad is using.
+    #[serde(default = "defaults::scratchpad_size")]
+    width: u8,
+
+    /// The percentage of the monitor height this scratchpad is using.
+    #[serde(default = "defaults::scratchpad_size")]
+    height: u8,
+}
+
+impl ScratchpadConfig {
+    /// Validates this configuration section.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        if self.class.is_none() && self.title.is_none() {
+            bail!("scratchpad {} needs a class or title to match", self.name)
+        }
+
+        if !(1..=100).contains(&self.width) || !(1..=100).contains(&self.height) {
+            bail!("size of scratchpad {} must be between 1 and 100", self.name)
+        }
+
+        Ok(())
+    }
+
+    /// Whether a window with the given WM_CLASS [`classes`] and [`title`] belongs to this
+    /// scratchpad.
+    pub fn matches(&self, classes: &[String], title: &str) -> bool {
+        matches_window(&self.class, &self.title, classes, title)
+    }
+
+    /// The geometry of this scratchpad, centered on the given [`monitor`].
+    pub fn geometry(&self, monitor: Geometry) -> Geometry {
+        let width = monitor.width() * self.width as u32 / 100;
+        let height = monitor.height() * self.height as u32 / 100;
+
+        Geometry::new(
+            monitor.x() + (monitor.width() - width) as i32 / 2,
+            monitor.y() + (monitor.height() - height) as i32 / 2,
+            width,
+            height,
+        )
+    }
+}
+
+/// Configuration of a rule for windows, matched by their class or title.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Getters)]
+pub struct WindowRuleConfig {
+    /// The WM_CLASS instance or class name of matching windows.
+    class: Option<String>,
+
+    /// The title of matching windows.
+    title: Option<String>,
+
+    /// Whether matching windows are floating.
+    floating: Option<bool>,
+
+    /// Whether matching windows are visible on all tags.
+    sticky: Option<bool>,
+
+    /// Whether matching windows are focused when they are mapped, which they are by default.
+    focus: Option<bool>,
+}
+
+impl WindowRuleConfig {
+    /// Validates this configuration section.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        if self.class.is_none() && self.title.is_none() {
+            bail!("window rules need a class or title to match")
+        }
+
+        Ok(())
+    }
+
+    /// Whether this rule applies to a window with the given WM_CLASS [`classes`] and [`title`].
+    pub fn matches(&self, classes: &[String], title: &str) -> bool {
+        matches_window(&self.class, &self.title, classes, title)
+    }
+
+    /// Applies this rule to the given [`state`] of a matching client.
+    pub fn apply(&self, state: &mut ClientState) {
+        if let Some(sticky) = self.sticky {
+            state.change_sticky(sticky);
+        }
+
+        if let Some(floating) = self.floating {
+            state.change_floating(floating);
+        }
+    }
+}
+
+/// Whether a window with the given WM_CLASS [`classes`] and [`title`] matches the given [`class`]
+/// and [`expected_title`], if they are set.
+fn matches_window(
+    class: &Option<String>,
+    expected_title: &Option<String>,
+    classes: &[String],
+    title: &str,
+) -> bool {
+    class.as_ref().is_none_or(|class| classes.contains(class))
+        && expected_title
+            .as_ref()
+            .is_none_or(|expected| expected == title)
+}
+
 static CONFIG: OnceLock<MainConfig> = OnceLock::new();
 
 /// Loads the configuration from the given configuration file, or `rwm.toml`.
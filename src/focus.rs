@@ -0,0 +1,323 @@
+use crate::prelude::*;
+
+wrapper!(FocusStack(Vec<Entity>));
+wrapper!(FocusedClient(Option<Entity>));
+
+impl FocusStack {
+    /// Moves the given [`client`] to the top of this stack.
+    pub fn focus(&mut self, client: Entity) {
+        self.remove_client(client);
+        self.push(client);
+    }
+
+    /// Removes the given [`client`] from this stack.
+    pub fn remove_client(&mut self, client: Entity) {
+        self.retain(|other| *other != client);
+    }
+
+    /// Replaces the given [`client`] with the given [`replacement`], keeping its position.
+    pub fn replace_client(&mut self, client: Entity, replacement: Entity) {
+        for other in self.iter_mut().filter(|other| **other == client) {
+            *other = replacement;
+        }
+    }
+
+    /// The most recently focused client of the given [`candidates`].
+    pub fn most_recent(&self, candidates: &[Entity]) -> Option<Entity> {
+        self.iter()
+            .rev()
+            .find(|client| candidates.contains(client))
+            .copied()
+    }
+}
+
+/// Selects and focuses the given [`client`] on the given [`monitor`] without raising it, making
+/// it the focused client that bindings act on.
+pub fn select_client(
+    monitor: Entity,
+    client: Entity,
+    window: Window,
+    commands: &mut Commands,
+    conn: &PlatformConnection,
+) {
+    commands.entity(monitor).insert(SelectedClient(client));
+    commands.insert_resource(FocusedClient(Some(client)));
+    RWMP::focus(window, conn);
+}
+
+/// Selects, focuses and raises the given [`client`] on the given [`monitor`]. Only focusing with
+/// the pointer leaves raising to the configuration.
+pub fn focus_client(
+    monitor: Entity,
+    client: Entity,
+    window: Window,
+    frame: Window,
+    commands: &mut Commands,
+    conn: &PlatformConnection,
+) {
+    select_client(monitor, client, window, commands, conn);
+    RWMP::raise(frame, conn);
+}
+
+pub fn handle_focus_cycle(
+    mut events: EventReader<KeybindTriggered>,
+    mut commands: Commands,
+    monitors: Query<(Entity, &Tags, Option<&SelectedClient>), With<Monitor>>,
+    clients: Query<
+        (&ClientWindow, &ClientFrame, &ClientState),
+        (With<Client>, Without<Fullscreened>),
+    >,
+    conn: Res<PlatformConnection>,
+) {
+    for event in events.read() {
+        let offset = match event.action() {
+            KeybindAction::FocusNext => 1,
+            KeybindAction::FocusPrevious => -1,
+            _ => continue,
+        };
+
+        for (monitor, tags, selected) in &monitors {
+            if event.monitor() != Some(monitor) {
+                continue;
+            }
+
+            let candidates = tags
+                .shown_with_sticky(|client| clients.get(client).ok().map(|(.., state)| state))
+                .into_iter()
+                .filter(|client| clients.contains(*client))
+                .collect::<Vec<Entity>>();
+
+            if candidates.is_empty() {
+                continue;
+            }
+
+            let next = selected
+                .and_then(|selected| candidates.iter().position(|client| client == &**selected))
+                .map_or(0, |idx| {
+                    (idx as i32 + offset).rem_euclid(candidates.len() as i32) as usize
+                });
+
+            let client = candidates[next];
+            let Ok((window, frame, _)) = clients.get(client) else {
+                continue;
+            };
+
+            focus_client(monitor, client, **window, **frame, &mut commands, &conn);
+        }
+    }
+}
+
+/// The shown clients of the given [`monitors`] whose [`geometry`] lies on their monitor, grouped by
+/// monitor. Clients the [`geometry`] function returns [`None`] for are left out.
+pub fn on_screen_clients<'a>(
+    monitors: impl IntoIterator<Item = (Entity, &'a Tags, &'a Geometry)>,
+    sticky: impl Fn(Entity) -> bool,
+    geometry: impl Fn(Entity) -> Option<Geometry>,
+) -> Vec<(Entity, Geometry, Vec<(Entity, Geometry)>)> {
+    monitors
+        .into_iter()
+        .map(|(monitor, tags, monitor_geometry)| {
+            let clients = tags
+                .shown_clients(&sticky)
+                .into_iter()
+                .filter_map(|client| Some((client, geometry(client)?)))
+                // e.g. columns scrolled out of the viewport
+                .filter(|(_, geometry)| {
+                    let (x, y) = geometry.center();
+                    monitor_geometry.contains(x, y)
+                })
+                .collect();
+
+            (monitor, *monitor_geometry, clients)
+        })
+        .collect()
+}
+
+/// The client in the given [`direction`] of the given [`client`] as it is arranged by the layout of
+/// its monitor, together with that monitor. Only layouts that move clients out of view, e.g. the
+/// scrolling layout, know neighbours that [`on_screen_clients`] leaves out.
+pub fn layout_target<'a>(
+    monitors: impl IntoIterator<Item = (Entity, &'a Tags)>,
+    client: Entity,
+    direction: Direction,
+    sticky: impl Fn(Entity) -> bool,
+    tiled: impl Fn(Entity) -> bool,
+) -> Option<(Entity, Entity)> {
+    let (monitor, tags) = monitors
+        .into_iter()
+        .find(|(_, tags)| tags.contains_client(client))?;
+    let tiled = tags.tiled_clients(sticky, tiled);
+    if !tiled.contains(&client) {
+        return None;
+    }
+
+    let target = layout_neighbour(tags.current()?, &tiled, client, direction)?;
+    Some((monitor, target))
+}
+
+/// The client in the given [`direction`] of the given [`client`], together with its monitor.
+/// Clients on the same monitor are preferred, otherwise the closest client on the neighbouring
+/// monitor in that direction is used.
+pub fn directional_target(
+    client: Entity,
+    direction: Direction,
+    monitors: &[(Entity, Geometry, Vec<(Entity, Geometry)>)],
+) -> Option<(Entity, Entity)> {
+    let (source, source_geometry, clients) = monitors
+        .iter()
+        .find(|(_, _, clients)| clients.iter().any(|(other, _)| *other == client))?;
+    let (_, geometry) = clients.iter().find(|(other, _)| *other == client)?;
+
+    let others = clients
+        .iter()
+        .copied()
+        .filter(|(other, _)| *other != client)
+        .collect::<Vec<(Entity, Geometry)>>();
+    if let Some(target) = geometry.neighbour(&others, direction) {
+        return Some((*source, target));
+    }
+
+    let neighbours = monitors
+        .iter()
+        .filter(|(monitor, ..)| monitor != source)
+        .map(|(monitor, monitor_geometry, _)| (*monitor, *monitor_geometry))
+        .collect::<Vec<(Entity, Geometry)>>();
+    let monitor = source_geometry.neighbour(&neighbours, direction)?;
+    let (_, _, clients) = monitors.iter().find(|(other, ..)| *other == monitor)?;
+
+    Some((monitor, geometry.neighbour(clients, direction)?))
+}
+
+pub fn handle_focus_direction(
+    mut events: EventReader<KeybindTriggered>,
+    mut commands: Commands,
+    monitors: Query<(Entity, &Tags, &Geometry), (With<Monitor>, Without<Client>)>,
+    clients: Query<
+        (&Geometry, &ClientWindow, &ClientFrame, &ClientState),
+        (With<Client>, Without<Fullscreened>, Without<Monitor>),
+    >,
+    conn: Res<PlatformConnection>,
+) {
+    for event in events.read() {
+        let KeybindAction::FocusDirection(direction) = event.action() else {
+            continue;
+        };
+
+        let Some(client) = event.client() else {
+            continue;
+        };
+
+        let sticky = |client| {
+            clients
+                .get(client)
+                .is_ok_and(|(_, _, _, state)| state.sticky())
+        };
+        let screen = on_screen_clients(&monitors, sticky, |client| {
+            clients.get(client).ok().map(|(geometry, ..)| *geometry)
+        });
+
+        let Some((monitor, target)) = layout_target(
+            monitors.iter().map(|(monitor, tags, _)| (monitor, tags)),
+            client,
+            *direction,
+            sticky,
+            |client| {
+                clients
+                    .get(client)
+                    .is_ok_and(|(_, _, _, state)| !state.floating())
+            },
+        )
+        .or_else(|| directional_target(client, *direction, &screen)) else {
+            continue;
+        };
+
+        let Ok((_, window, frame, _)) = clients.get(target) else {
+            continue;
+        };
+
+        focus_client(monitor, target, **window, **frame, &mut commands, &conn);
+    }
+}
+
+/// Records every newly selected client in the focus stack of its monitor.
+pub fn track_focus(
+    mut monitors: Query<
+        (&SelectedClient, &mut FocusStack),
+        (With<Monitor>, Changed<SelectedClient>),
+    >,
+) {
+    for (selected, mut stack) in &mut monitors {
+        stack.focus(**selected);
+    }
+}
+
+/// Grabs clicks on every client except the focused one if clicking focuses clients, so that the
+/// first click on an unfocused client focuses it.
+pub fn update_focus_grabs(
+    clients: Query<(Entity, &ClientWindow), With<Client>>,
+    managed: Query<(), Added<Client>>,
+    focused: Res<FocusedClient>,
+    config: Res<MainConfig>,
+    conn: Res<PlatformConnection>,
+) {
+    if config.focus().policy() != FocusPolicy::Click
+        || (!focused.is_changed() && managed.is_empty())
+    {
+        return;
+    }
+
+    for (client, window) in &clients {
+        RWMP::grab_focus_clicks(**window, **focused != Some(client), &conn);
+    }
+}
+
+/// Selects the most recently focused client of a monitor again once its selected client is no
+/// longer shown, e.g. because it was unmanaged or its tag is no longer viewed. Only the monitor
+/// owning the [`FocusedClient`] focuses the client, the others merely select it.
+pub fn restore_focus(
+    mut commands: Commands,
+    monitors: Query<(Entity, &Tags, &FocusStack, Option<&SelectedClient>), With<Monitor>>,
+    clients: Query<
+        (&ClientWindow, &ClientFrame, &ClientState),
+        (With<Client>, Without<Fullscreened>),
+    >,
+    focused: Res<FocusedClient>,
+    conn: Res<PlatformConnection>,
+) {
+    for (monitor, tags, stack, selected) in &monitors {
+        let shown =
+            tags.shown_with_sticky(|client| clients.get(client).ok().map(|(.., state)| state));
+
+        if selected.is_some_and(|selected| shown.contains(selected)) {
+            continue;
+        }
+
+        let candidates = shown
+            .into_iter()
+            .filter(|client| clients.contains(*client))
+            .collect::<Vec<Entity>>();
+
+        let Some(client) = stack
+            .most_recent(&candidates)
+            .or_else(|| candidates.first().copied())
+        else {
+            if selected.is_some() {
+                commands.entity(monitor).remove::<SelectedClient>();
+            }
+            continue;
+        };
+
+        let owns_focus =
+            focused.is_none() || selected.is_some_and(|selected| Some(**selected) == **focused);
+        if !owns_focus {
+            commands.entity(monitor).insert(SelectedClient(client));
+            continue;
+        }
+
+        let Ok((window, frame, _)) = clients.get(client) else {
+            continue;
+        };
+
+        focus_client(monitor, client, **window, **frame, &mut commands, &conn);
+    }
+}
@@ -1,7 +1,47 @@
+use serde::Deserialize;
+
 use crate::prelude::*;
 
 wrapper!(OriginalGeometry(Geometry));
 
+/// A direction on the screen.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Direction {
+    #[serde(rename = "left")]
+    Left,
+
+    #[serde(rename = "right")]
+    Right,
+
+    #[serde(rename = "up")]
+    Up,
+
+    #[serde(rename = "down")]
+    Down,
+}
+
+impl Direction {
+    /// The opposite direction.
+    pub fn opposite(self) -> Self {
+        match self {
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+        }
+    }
+
+    /// The direction with the x and y axis swapped.
+    pub fn transposed(self) -> Self {
+        match self {
+            Direction::Left => Direction::Up,
+            Direction::Right => Direction::Down,
+            Direction::Up => Direction::Left,
+            Direction::Down => Direction::Right,
+        }
+    }
+}
+
 /// Generic struct for geometry.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Getters, Component)]
 #[constructor(named(new), fields(x, y, width, height))]
@@ -41,4 +81,203 @@ impl Geometry {
     pub fn contains(&self, x: i32, y: i32) -> bool {
         x >= self.x && x < self.right() && y >= self.y && y < self.bottom()
     }
+
+    /// Shrinks this geometry object by the given amount on each side.
+    pub fn inset(&self, left: u32, right: u32, top: u32, bottom: u32) -> Geometry {
+        Geometry::new(
+            self.x + left as i32,
+            self.y + top as i32,
+            self.width.saturating_sub(left + right),
+            self.height.saturating_sub(top + bottom),
+        )
+    }
+
+    /// Splits this geometry object into [`count`] rows of equal height, separated by [`gap`].
+    pub fn split_rows(&self, count: usize, gap: u32) -> Vec<Geometry> {
+        self.split_rows_weighted(&vec![1.0; count], gap)
+    }
+
+    /// Splits this geometry object into rows separated by [`gap`], with heights proportional to
+    /// the given [`weights`].
+    pub fn split_rows_weighted(&self, weights: &[f32], gap: u32) -> Vec<Geometry> {
+        let count = weights.len();
+        if count == 0 {
+            return vec![];
+        }
+
+        let available = self.height.saturating_sub(gap * (count as u32 - 1));
+        let total = weights.iter().sum::<f32>();
+        let mut y = self.y;
+
+        (0..count)
+            .map(|idx| {
+                let height = if idx == count - 1 {
+                    (self.bottom() - y).max(0) as u32
+                } else if total > 0.0 {
+                    (available as f32 * weights[idx] / total) as u32
+                } else {
+                    available / count as u32
+                };
+
+                let row = Geometry::new(self.x, y, self.width, height);
+                y += (height + gap) as i32;
+                row
+            })
+            .collect()
+    }
+
+    /// Splits this geometry object into [`count`] columns of equal width, separated by [`gap`].
+    pub fn split_columns(&self, count: usize, gap: u32) -> Vec<Geometry> {
+        self.transposed()
+            .split_rows(count, gap)
+            .into_iter()
+            .map(|column| column.transposed())
+            .collect()
+    }
+
+    /// Splits this geometry object into columns separated by [`gap`], with widths proportional to
+    /// the given [`weights`].
+    pub fn split_columns_weighted(&self, weights: &[f32], gap: u32) -> Vec<Geometry> {
+        self.transposed()
+            .split_rows_weighted(weights, gap)
+            .into_iter()
+            .map(|column| column.transposed())
+            .collect()
+    }
+
+    /// Splits this geometry object into two parts separated by [`gap`], the first one using
+    /// [`factor`] of the space. Vertical splits place the parts next to each other, horizontal
+    /// splits place them above each other.
+    pub fn split(&self, vertical: bool, factor: f32, gap: u32) -> (Geometry, Geometry) {
+        let area = if vertical { *self } else { self.transposed() };
+
+        let first_width = (area.width.saturating_sub(gap) as f32 * factor) as u32;
+        let first = Geometry::new(area.x, area.y, first_width, area.height);
+        let second_x = first.right() + gap as i32;
+        let second = Geometry::new(
+            second_x,
+            area.y,
+            (area.right() - second_x).max(0) as u32,
+            area.height,
+        );
+
+        if vertical {
+            (first, second)
+        } else {
+            (first.transposed(), second.transposed())
+        }
+    }
+
+    /// The center point of this geometry object.
+    pub fn center(&self) -> (i32, i32) {
+        (
+            self.x + self.width as i32 / 2,
+            self.y + self.height as i32 / 2,
+        )
+    }
+
+    /// Finds the closest of the given [`candidates`] in the given [`direction`]. Candidates that
+    /// overlap with this geometry object on the other axis are preferred.
+    pub fn neighbour<T: Copy>(
+        &self,
+        candidates: &[(T, Geometry)],
+        direction: Direction,
+    ) -> Option<T> {
+        let (x, y) = self.center();
+
+        candidates
+            .iter()
+            .filter_map(|(candidate, geometry)| {
+                let (other_x, other_y) = geometry.center();
+                let same_row = geometry.y < self.bottom() && geometry.bottom() > self.y;
+                let same_column = geometry.x < self.right() && geometry.right() > self.x;
+
+                let (distance, offset, overlapping) = match direction {
+                    Direction::Left => (x - other_x, other_y - y, same_row),
+                    Direction::Right => (other_x - x, other_y - y, same_row),
+                    Direction::Up => (y - other_y, other_x - x, same_column),
+                    Direction::Down => (other_y - y, other_x - x, same_column),
+                };
+
+                (distance > 0).then_some(((!overlapping, distance, offset.abs()), *candidate))
+            })
+            .min_by_key(|(score, _)| *score)
+            .map(|(_, candidate)| candidate)
+    }
+
+    /// The edge of this geometry object that is closest to the given point, if it is at most
+    /// [`margin`] away from it.
+    pub fn nearest_edge(&self, x: i32, y: i32, margin: u32) -> Option<Direction> {
+        [
+            (Direction::Left, (x - self.x).abs()),
+            (Direction::Right, (self.right() - x).abs()),
+            (Direction::Up, (y - self.y).abs()),
+            (Direction::Down, (self.bottom() - y).abs()),
+        ]
+        .into_iter()
+        .filter(|(_, distance)| *distance <= margin as i32)
+        .min_by_key(|(_, distance)| *distance)
+        .map(|(edge, _)| edge)
+    }
+
+    /// This geometry object moved left by twice its width, where it is out of view of any monitor
+    /// starting at the origin.
+    pub fn offscreen(&self) -> Geometry {
+        Geometry::new(-2 * self.width as i32 - 1, self.y, self.width, self.height)
+    }
+
+    /// Swaps the x and y axis of this geometry object.
+    pub fn transposed(&self) -> Geometry {
+        Geometry::new(self.y, self.x, self.height, self.width)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn heights(rows: &[Geometry]) -> Vec<u32> {
+        rows.iter().map(Geometry::height).collect()
+    }
+
+    #[test]
+    fn split_rows_weighted_gives_the_remainder_to_the_last_row() {
+        let area = Geometry::new(0, 0, 100, 100);
+        let rows = area.split_rows_weighted(&[1.0, 1.0, 1.0], 0);
+
+        assert_eq!(heights(&rows), vec![33, 33, 34]);
+        assert_eq!(rows.last().map(Geometry::bottom), Some(area.bottom()));
+    }
+
+    #[test]
+    fn split_rows_weighted_rounds_down_by_weight() {
+        let area = Geometry::new(0, 10, 50, 101);
+        let rows = area.split_rows_weighted(&[1.0, 3.0], 0);
+
+        assert_eq!(heights(&rows), vec![25, 76]);
+        assert_eq!(rows[1].y(), 35);
+    }
+
+    #[test]
+    fn split_rows_weighted_leaves_gaps_between_rows() {
+        let area = Geometry::new(0, 0, 100, 100);
+        let rows = area.split_rows_weighted(&[1.0, 1.0, 1.0], 5);
+
+        assert_eq!(heights(&rows), vec![30, 30, 30]);
+        assert_eq!(
+            rows.iter().map(Geometry::y).collect::<Vec<i32>>(),
+            vec![0, 35, 70]
+        );
+    }
+
+    #[test]
+    fn split_rows_weighted_shares_equally_without_weights() {
+        let area = Geometry::new(0, 0, 100, 90);
+
+        assert_eq!(
+            heights(&area.split_rows_weighted(&[0.0, 0.0], 0)),
+            vec![45, 45]
+        );
+        assert!(area.split_rows_weighted(&[], 0).is_empty());
+    }
 }
@@ -0,0 +1,297 @@
+use serde::Deserialize;
+
+use crate::prelude::*;
+
+wrapper!(KeyTyped(String));
+
+/// The keys hint labels are made of, starting with the home row.
+const HINT_KEYS: &str = "asdfghjklqwertyuiopzxcvbnm";
+
+/// What happens to the client whose hint label is typed.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum HintAction {
+    #[serde(rename = "focus")]
+    Focus,
+
+    #[serde(rename = "swap")]
+    Swap,
+
+    #[serde(rename = "close")]
+    Close,
+}
+
+/// A label shown on top of a client while hint mode is active.
+#[derive(Debug, Clone, PartialEq, Eq, Getters)]
+pub struct Hint {
+    /// The keys to type for choosing the client.
+    label: String,
+
+    /// The labelled client.
+    client: Entity,
+
+    /// The popup showing the label.
+    #[getter(copy)]
+    popup: Window,
+}
+
+/// The state of hint mode, which labels every visible client and lets the user choose one by
+/// typing its label.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq, Getters)]
+pub struct Hints {
+    /// What happens to the chosen client, if hint mode is active.
+    action: Option<HintAction>,
+
+    /// The client that was focused when hint mode started.
+    origin: Option<Entity>,
+
+    /// The hints whose labels start with the keys typed so far.
+    hints: Vec<Hint>,
+
+    /// The keys typed so far.
+    typed: String,
+}
+
+/// Labels of equal length for the given amount of clients, so that no label is the prefix of
+/// another one.
+pub fn hint_labels(count: usize) -> Vec<String> {
+    let keys = HINT_KEYS.chars().collect::<Vec<char>>();
+    let mut length = 1;
+    while keys.len().pow(length) < count {
+        length += 1;
+    }
+
+    (0..count)
+        .map(|mut idx| {
+            let mut label = String::new();
+            for _ in 0..length {
+                label.insert(0, keys[idx % keys.len()]);
+                idx /= keys.len();
+            }
+            label
+        })
+        .collect()
+}
+
+/// Destroys the popups of the given [`hints`] and ungrabs the keyboard.
+fn close_hints(hints: &mut Hints, conn: &PlatformConnection) {
+    for hint in &hints.hints {
+        RWMP::destroy_popup(hint.popup, conn);
+    }
+
+    RWMP::ungrab_keyboard(conn);
+    *hints = Hints::default();
+}
+
+pub fn handle_hint_mode(
+    mut events: EventReader<KeybindTriggered>,
+    mut hints: ResMut<Hints>,
+    monitors: Query<(Entity, &Tags, &Geometry), (With<Monitor>, Without<Client>)>,
+    clients: Query<(&Geometry, &ClientState), (With<Client>, Without<Fullscreened>)>,
+    conn: Res<PlatformConnection>,
+    config: Res<MainConfig>,
+    root_window: Res<MainRootWindow>,
+) {
+    for event in events.read() {
+        let KeybindAction::Hint(action) = event.action() else {
+            continue;
+        };
+
+        if hints.action.is_some() {
+            close_hints(&mut hints, &conn);
+        }
+
+        let screen = on_screen_clients(
+            &monitors,
+            |client| clients.get(client).is_ok_and(|(_, state)| state.sticky()),
+            |client| clients.get(client).ok().map(|(geometry, _)| *geometry),
+        );
+
+        let labelled = screen
+            .into_iter()
+            .flat_map(|(_, _, clients)| clients)
+            .collect::<Vec<(Entity, Geometry)>>();
+        if labelled.is_empty() {
+            continue;
+        }
+
+        let height = config.tabs().height() as u32;
+        let mut shown = vec![];
+        for ((client, geometry), label) in labelled.iter().zip(hint_labels(labelled.len())) {
+            // roughly the width of the label in a fixed font
+            let width = height * label.len() as u32;
+            let area = Geometry::new(geometry.x(), geometry.y(), width, height);
+
+            match RWMP::create_popup(area, **root_window, &conn) {
+                Ok(popup) => shown.push(Hint {
+                    label,
+                    client: *client,
+                    popup,
+                }),
+                Err(e) => error!("failed showing hint {label}: {e}"),
+            }
+        }
+
+        RWMP::grab_keyboard(**root_window, &conn);
+        *hints = Hints {
+            action: Some(*action),
+            origin: event.client(),
+            hints: shown,
+            typed: String::new(),
+        };
+    }
+}
+
+pub fn handle_hint_keys(
+    mut events: EventReader<KeyTyped>,
+    mut commands: Commands,
+    mut hints: ResMut<Hints>,
+    mut monitors: Query<
+        (Entity, &mut Tags, &Geometry, Option<&mut SelectedClient>),
+        (With<Monitor>, Without<Client>),
+    >,
+    clients: Query<(&ClientWindow, &ClientFrame), With<Client>>,
+    conn: Res<PlatformConnection>,
+) {
+    for event in events.read() {
+        let Some(action) = hints.action else {
+            continue;
+        };
+
+        if **event == "escape" {
+            close_hints(&mut hints, &conn);
+            continue;
+        }
+
+        let typed = format!("{}{}", hints.typed, **event);
+        // keys that are not part of any remaining label are ignored
+        if !hints
+            .hints
+            .iter()
+            .any(|hint| hint.label.starts_with(&typed))
+        {
+            continue;
+        }
+
+        let Some(chosen) = hints
+            .hints
+            .iter()
+            .find(|hint| hint.label == typed)
+            .map(|hint| hint.client)
+        else {
+            for hint in &hints.hints {
+                if !hint.label.starts_with(&typed) {
+                    RWMP::destroy_popup(hint.popup, &conn);
+                }
+            }
+
+            hints.hints.retain(|hint| hint.label.starts_with(&typed));
+            hints.typed = typed;
+            continue;
+        };
+
+        let origin = hints.origin;
+        close_hints(&mut hints, &conn);
+
+        let Ok((window, frame)) = clients.get(chosen) else {
+            continue;
+        };
+
+        match action {
+            HintAction::Focus => {
+                let Some((monitor, ..)) = monitors
+                    .iter()
+                    .find(|(_, tags, ..)| tags.contains_client(chosen))
+                else {
+                    continue;
+                };
+
+                focus_client(monitor, chosen, **window, **frame, &mut commands, &conn);
+            }
+            HintAction::Swap => {
+                let Some(origin) = origin.filter(|origin| *origin != chosen) else {
+                    continue;
+                };
+
+                let Ok((origin_window, origin_frame)) = clients.get(origin) else {
+                    continue;
+                };
+
+                swap_clients(
+                    origin,
+                    chosen,
+                    **origin_window,
+                    **origin_frame,
+                    &mut monitors,
+                    &mut commands,
+                    &conn,
+                );
+            }
+            HintAction::Close => RWMP::close(**window, &conn),
+        }
+    }
+}
+
+/// Draws the labels of all hints that still match the typed keys.
+pub fn draw_hints(hints: Res<Hints>, conn: Res<PlatformConnection>, config: Res<MainConfig>) {
+    if !hints.is_changed() {
+        return;
+    }
+
+    let height = config.tabs().height() as u32;
+    for hint in &hints.hints {
+        RWMP::draw_tabs(
+            &config,
+            hint.popup,
+            height * hint.label.len() as u32,
+            &[(hint.label.clone(), ColorScheme::Selected)],
+            TabStyle::Stacked,
+            &conn,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_unique_and_prefix_free(labels: &[String]) {
+        for (idx, label) in labels.iter().enumerate() {
+            for (other_idx, other) in labels.iter().enumerate() {
+                if idx != other_idx {
+                    assert!(!other.starts_with(label.as_str()), "{label} and {other}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn few_clients_get_single_key_labels() {
+        let labels = hint_labels(HINT_KEYS.len());
+
+        assert_eq!(labels.len(), HINT_KEYS.len());
+        assert!(labels.iter().all(|label| label.len() == 1));
+        assert_eq!(labels[0], "a");
+        assert_unique_and_prefix_free(&labels);
+    }
+
+    #[test]
+    fn more_clients_get_longer_labels() {
+        let labels = hint_labels(HINT_KEYS.len() + 1);
+
+        assert!(labels.iter().all(|label| label.len() == 2));
+        assert_unique_and_prefix_free(&labels);
+    }
+
+    #[test]
+    fn labels_stay_prefix_free_for_many_clients() {
+        let labels = hint_labels(1000);
+
+        assert!(labels.iter().all(|label| label.len() == 3));
+        assert_unique_and_prefix_free(&labels);
+    }
+
+    #[test]
+    fn no_clients_get_no_labels() {
+        assert!(hint_labels(0).is_empty());
+    }
+}
@@ -13,14 +13,137 @@ pub enum KeybindAction {
 
     #[serde(rename = "shell")]
     Shell(String),
+
+    #[serde(rename = "set_layout")]
+    SetLayout(String),
+
+    #[serde(rename = "adjust_gaps")]
+    AdjustGaps(i32),
+
+    #[serde(rename = "adjust_master_count")]
+    AdjustMasterCount(i32),
+
+    #[serde(rename = "set_master_factor")]
+    SetMasterFactor(i32),
+
+    #[serde(rename = "adjust_master_factor")]
+    AdjustMasterFactor(i32),
+
+    #[serde(rename = "reset_master")]
+    ResetMaster,
+
+    #[serde(rename = "adjust_client_weight")]
+    AdjustClientWeight(i32),
+
+    #[serde(rename = "reset_client_weight")]
+    ResetClientWeight,
+
+    #[serde(rename = "mirror_layout")]
+    MirrorLayout,
+
+    #[serde(rename = "flip_layout")]
+    FlipLayout,
+
+    #[serde(rename = "rotate_layout")]
+    RotateLayout,
+
+    #[serde(rename = "reset_layout_transform")]
+    ResetLayoutTransform,
+
+    #[serde(rename = "view_tag")]
+    ViewTag(u8),
+
+    #[serde(rename = "toggle_view_tag")]
+    ToggleViewTag(u8),
+
+    #[serde(rename = "view_previous_tagset")]
+    ViewPreviousTagset,
+
+    #[serde(rename = "send_to_tag")]
+    SendToTag(u8),
+
+    #[serde(rename = "follow_to_tag")]
+    FollowToTag(u8),
+
+    #[serde(rename = "toggle_client_tag")]
+    ToggleClientTag(u8),
+
+    #[serde(rename = "view_workspace")]
+    ViewWorkspace(String),
+
+    #[serde(rename = "rename_workspace")]
+    RenameWorkspace(String),
+
+    #[serde(rename = "toggle_scratchpad")]
+    ToggleScratchpad(String),
+
+    #[serde(rename = "toggle_sticky")]
+    ToggleSticky,
+
+    #[serde(rename = "focus_next")]
+    FocusNext,
+
+    #[serde(rename = "focus_previous")]
+    FocusPrevious,
+
+    #[serde(rename = "focus_direction")]
+    FocusDirection(Direction),
+
+    #[serde(rename = "swap_next")]
+    SwapNext,
+
+    #[serde(rename = "swap_previous")]
+    SwapPrevious,
+
+    #[serde(rename = "swap_direction")]
+    SwapDirection(Direction),
+
+    #[serde(rename = "zoom")]
+    Zoom,
+
+    #[serde(rename = "switch_client")]
+    SwitchClient(i32),
+
+    #[serde(rename = "hint")]
+    Hint(HintAction),
+
+    #[serde(rename = "preselect")]
+    Preselect(Direction),
+
+    #[serde(rename = "adjust_split_ratio")]
+    AdjustSplitRatio(i32),
+
+    #[serde(rename = "rotate_split")]
+    RotateSplit,
+
+    #[serde(rename = "flip_split")]
+    FlipSplit(SplitAxis),
+
+    #[serde(rename = "move_to_container")]
+    MoveToContainer(Direction),
+
+    #[serde(rename = "adjust_column_width")]
+    AdjustColumnWidth(i32),
+
+    #[serde(rename = "consume_into_column")]
+    ConsumeIntoColumn,
+
+    #[serde(rename = "expel_from_column")]
+    ExpelFromColumn,
 }
 
 /// An event that gets triggered when a keybind gets triggered and should execute a [`KeybindAction`].
 #[derive(Event, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Getters, Component)]
-#[constructor(named(new), fields(action, client))]
+#[constructor(named(new), fields(action, client, monitor))]
 pub struct KeybindTriggered {
     action: KeybindAction,
+    #[getter(copy)]
     client: Option<Entity>,
+
+    /// The monitor the binding acts on: the monitor of the [`client`](Self::client) if there is
+    /// one, otherwise the monitor under the pointer.
+    #[getter(copy)]
+    monitor: Option<Entity>,
 }
 
 pub fn handle_shell(mut events: EventReader<KeybindTriggered>) {
@@ -1,13 +1,229 @@
-use std::fmt::{self, Debug, Formatter};
+use std::{
+    fmt::{self, Debug, Formatter},
+    sync::Arc,
+};
 
 use crate::prelude::*;
 
-#[derive(Clone, Copy, PartialEq, Getters, PartialOrd)]
-#[constructor(named(new), fields(symbol, name, manage_fn))]
+wrapper!(Layouts(Vec<Layout>));
+
+/// Computes the geometries of the clients of a tag. Layout engines should not have any side effects,
+/// applying the geometries is done by [`arrange`].
+pub trait LayoutEngine: Send + Sync + 'static {
+    /// Updates the layout state stored in the given [`tag`] before its tiled [`clients`] are
+    /// arranged in the given [`area`].
+    fn prepare(&self, _area: Geometry, _clients: &[LayoutClient], _tag: &mut Tag) {}
+
+    /// Arranges the given tiled [`clients`] of the given [`tag`] in the given [`area`] and returns the
+    /// geometry each client should occupy, including its border. Clients that are left out are out
+    /// of view and moved off-screen.
+    fn arrange(
+        &self,
+        area: Geometry,
+        clients: &[LayoutClient],
+        tag: &Tag,
+    ) -> Vec<(Entity, Geometry)>;
+
+    /// The symbol to display for the given [`clients`] of the given [`tag`]. Returns [`None`] to use
+    /// the static symbol of the [`Layout`].
+    fn symbol(&self, _clients: &[LayoutClient], _tag: &Tag) -> Option<String> {
+        None
+    }
+
+    /// The style of the tab bar drawn above every client, if this layout has one.
+    fn tabs(&self) -> Option<TabStyle> {
+        None
+    }
+
+    /// Whether this layout places clients on top of each other, so that the selected client has to
+    /// be raised to be visible at all. Layouts with tab bars stack their clients.
+    fn stacks_clients(&self) -> bool {
+        self.tabs().is_some()
+    }
+
+    /// Resizes the layout of the given [`tag`] by dragging the given [`edge`] of the given
+    /// [`client`] to the given position. The [`tiled`] clients are arranged in the given [`area`].
+    fn resize(
+        &self,
+        _area: Geometry,
+        _tiled: &[Entity],
+        _tag: &mut Tag,
+        _client: Entity,
+        _edge: Direction,
+        _x: i32,
+        _y: i32,
+    ) {
+    }
+
+    /// The client in the given [`direction`] of the given [`client`] among the [`tiled`] clients
+    /// of the given [`tag`], for layouts that move clients out of view. Returns [`None`] to find the
+    /// neighbour by the geometries on the screen instead.
+    fn neighbour(
+        &self,
+        _tiled: &[Entity],
+        _tag: &Tag,
+        _client: Entity,
+        _direction: Direction,
+    ) -> Option<Entity> {
+        None
+    }
+}
+
+/// A client as it is passed to a [`LayoutEngine`].
+#[derive(Debug, Clone, Copy, PartialEq, Getters)]
+#[constructor(named(new), fields(entity, size_hints, state, weight, focused))]
+pub struct LayoutClient {
+    /// The client entity.
+    #[getter(copy)]
+    entity: Entity,
+
+    /// The size hints of the client.
+    size_hints: SizeHints,
+
+    /// The state of the client.
+    state: ClientState,
+
+    /// The weight of the client, determining its share of the space in stack layouts.
+    weight: f32,
+
+    /// Whether the client is the selected client of its monitor.
+    focused: bool,
+}
+
+impl LayoutClient {
+    /// The weights of the given [`clients`], in order.
+    pub fn weights(clients: &[LayoutClient]) -> Vec<f32> {
+        clients.iter().map(LayoutClient::weight).collect()
+    }
+}
+
+/// A transform applied to the geometries produced by any layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Getters, Default)]
+pub struct LayoutTransform {
+    /// Whether the layout is mirrored horizontally.
+    mirrored: bool,
+
+    /// Whether the layout is flipped vertically.
+    flipped: bool,
+
+    /// Whether the layout is rotated by 90 degrees, swapping its x and y axis.
+    rotated: bool,
+}
+
+impl LayoutTransform {
+    /// Toggles mirroring the layout horizontally.
+    pub fn toggle_mirrored(&mut self) -> &mut Self {
+        self.mirrored = !self.mirrored;
+        self
+    }
+
+    /// Toggles flipping the layout vertically.
+    pub fn toggle_flipped(&mut self) -> &mut Self {
+        self.flipped = !self.flipped;
+        self
+    }
+
+    /// Toggles rotating the layout by 90 degrees.
+    pub fn toggle_rotated(&mut self) -> &mut Self {
+        self.rotated = !self.rotated;
+        self
+    }
+
+    /// The area a layout should arrange its clients in, so that [`LayoutTransform::apply`] maps
+    /// the result into the given [`area`].
+    pub fn area(&self, area: Geometry) -> Geometry {
+        if self.rotated {
+            area.transposed()
+        } else {
+            area
+        }
+    }
+
+    /// Maps the given [`cell`], arranged in [`LayoutTransform::area`], back into the given
+    /// [`area`].
+    pub fn apply(&self, area: Geometry, cell: Geometry) -> Geometry {
+        let cell = if self.rotated {
+            cell.transposed()
+        } else {
+            cell
+        };
+
+        let x = if self.mirrored {
+            area.x() + area.right() - cell.right()
+        } else {
+            cell.x()
+        };
+
+        let y = if self.flipped {
+            area.y() + area.bottom() - cell.bottom()
+        } else {
+            cell.y()
+        };
+
+        Geometry::new(x, y, cell.width(), cell.height())
+    }
+
+    /// Maps the given point in the given [`area`] into [`LayoutTransform::area`].
+    pub fn point(&self, area: Geometry, x: i32, y: i32) -> (i32, i32) {
+        let x = if self.mirrored {
+            area.x() + area.right() - x
+        } else {
+            x
+        };
+
+        let y = if self.flipped {
+            area.y() + area.bottom() - y
+        } else {
+            y
+        };
+
+        if self.rotated { (y, x) } else { (x, y) }
+    }
+
+    /// Maps the given [`direction`] on the screen into [`LayoutTransform::area`].
+    pub fn direction(&self, direction: Direction) -> Direction {
+        let direction = match direction {
+            Direction::Left | Direction::Right if self.mirrored => direction.opposite(),
+            Direction::Up | Direction::Down if self.flipped => direction.opposite(),
+            _ => direction,
+        };
+
+        if self.rotated {
+            direction.transposed()
+        } else {
+            direction
+        }
+    }
+}
+
+/// A named layout that can be used by a tag.
+#[derive(Clone, Getters)]
 pub struct Layout {
+    /// The symbol of this layout.
     symbol: &'static str,
+
+    /// The unique name of this layout.
     name: &'static str,
-    manage_fn: fn(Tag, Monitor),
+
+    /// The engine computing the geometries of this layout.
+    engine: Arc<dyn LayoutEngine>,
+}
+
+impl Layout {
+    /// Creates a new layout with the given [`engine`].
+    pub fn new(symbol: &'static str, name: &'static str, engine: impl LayoutEngine) -> Self {
+        Self {
+            symbol,
+            name,
+            engine: Arc::new(engine),
+        }
+    }
+}
+
+impl PartialEq for Layout {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+    }
 }
 
 impl Debug for Layout {
@@ -16,4 +232,577 @@ impl Debug for Layout {
     }
 }
 
-pub fn test_layout(_tag: Tag, _mon: Monitor) {}
+impl FromWorld for Layouts {
+    fn from_world(world: &mut World) -> Self {
+        Layouts(builtin_layouts(world.resource::<MainConfig>()))
+    }
+}
+
+impl Layouts {
+    /// Gets the layout with the given [`name`].
+    pub fn get(&self, name: &str) -> Option<Layout> {
+        self.iter().find(|layout| layout.name() == name).cloned()
+    }
+
+    /// The default layout, which is the first registered one.
+    pub fn default_layout(&self) -> Layout {
+        self.first()
+            .cloned()
+            .unwrap_or_else(|| Layout::new("><>", "float", TestLayout))
+    }
+}
+
+/// All layouts that are shipped with rwm, set up with the given [`config`].
+pub fn builtin_layouts(config: &MainConfig) -> Vec<Layout> {
+    let column_width = config.scrolling().column_width() as f32 / 100.0;
+
+    vec![
+        Layout::new("[]=", "tile", MasterStack),
+        Layout::new("[M]", "monocle", Monocle),
+        Layout::new("###", "grid", Grid),
+        Layout::new("TTT", "bstack", BottomStack),
+        Layout::new("|M|", "centeredmaster", CenteredMaster),
+        Layout::new("[@]", "spiral", Spiral),
+        Layout::new("[\\]", "dwindle", Dwindle),
+        Layout::new("[T]", "tree", Tree),
+        Layout::new("[S]", "scrolling", Scrolling::new(column_width)),
+        Layout::new("[-]", "tabbed", Tabbed),
+        Layout::new("[=]", "stacked", Stacked),
+        Layout::new("><>", "float", TestLayout),
+    ]
+}
+
+/// Extension trait for registering layouts on an [`App`].
+pub trait LayoutAppExt {
+    /// Registers the given [`layout`], making it selectable for tags. Layouts registered with a
+    /// name that already exists replace the existing layout.
+    fn register_layout(&mut self, layout: Layout) -> &mut Self;
+}
+
+impl LayoutAppExt for App {
+    fn register_layout(&mut self, layout: Layout) -> &mut Self {
+        let mut layouts = self.world_mut().get_resource_or_init::<Layouts>();
+        layouts.retain(|existing| existing.name() != layout.name());
+        layouts.push(layout);
+        self
+    }
+}
+
+/// A layout that leaves all clients where they are.
+pub struct TestLayout;
+
+impl LayoutEngine for TestLayout {
+    fn arrange(
+        &self,
+        _area: Geometry,
+        _clients: &[LayoutClient],
+        _tag: &Tag,
+    ) -> Vec<(Entity, Geometry)> {
+        vec![]
+    }
+}
+
+/// Re-arranges the tiled clients of every monitor whose tags or selected client have changed,
+/// e.g. because a client was managed or unmanaged. All clients of the viewed tags are arranged
+/// with the layout of the first viewed tag, clients of other tags are moved off-screen unless they
+/// are sticky.
+pub fn arrange(
+    mut monitors: Query<
+        (&mut Tags, &Geometry, Option<&SelectedClient>),
+        (With<Monitor>, Or<(Changed<Tags>, Changed<SelectedClient>)>),
+    >,
+    mut clients: Query<
+        (
+            &mut Geometry,
+            &ClientWindow,
+            &ClientFrame,
+            &ClientState,
+            &ClientName,
+            Option<&SizeHints>,
+            Option<&ClientWeight>,
+            Has<Fullscreened>,
+            Has<Hidden>,
+        ),
+        (With<Client>, Without<Monitor>),
+    >,
+    mut commands: Commands,
+    mut published_symbol: Local<Option<String>>,
+    conn: Res<PlatformConnection>,
+    config: Res<MainConfig>,
+    root_window: Res<MainRootWindow>,
+) {
+    let border_width = config.border().width() as u32;
+    let tab_height = config.tabs().height() as u32;
+
+    for (mut tags, monitor_geometry, selected) in &mut monitors {
+        // the symbol is derived from the arrangement, so changing it must not arrange again
+        let tags = tags.bypass_change_detection();
+        tags.update_occupied();
+        // sticky clients stay visible whatever tags are viewed
+        let visible = tags
+            .shown_with_sticky(|client| clients.get(client).ok().map(|(_, _, _, state, ..)| state));
+
+        for client in tags.all_clients() {
+            let Ok((geometry, window, frame, .., fullscreened, hidden)) = clients.get(client)
+            else {
+                continue;
+            };
+
+            let top_level = if fullscreened { **window } else { **frame };
+            match (visible.contains(&client), hidden) {
+                (false, false) => {
+                    RWMP::move_offscreen(*geometry, top_level, &conn);
+                    commands.entity(client).insert(Hidden);
+                }
+                (true, true) => {
+                    // restores floating clients, tiled clients are arranged again below
+                    if fullscreened {
+                        RWMP::update_client_geometry(*geometry, **window, &conn);
+                    } else {
+                        RWMP::update_bordered_client_geometry(
+                            &config, *geometry, **window, **frame, &conn,
+                        );
+                    }
+
+                    commands.entity(client).remove::<Hidden>();
+                }
+                _ => {}
+            }
+        }
+
+        let selected = selected.map(|selected| **selected);
+        let tiled = tags
+            .tiled_clients(
+                |client| {
+                    clients
+                        .get(client)
+                        .is_ok_and(|(_, _, _, state, ..)| state.sticky())
+                },
+                |client| {
+                    clients
+                        .get(client)
+                        .is_ok_and(|(_, _, _, state, .., fullscreened, _)| {
+                            !state.floating() && !fullscreened
+                        })
+                },
+            )
+            .into_iter()
+            .filter_map(|client| {
+                let (_, _, _, state, _, size_hints, weight, ..) = clients.get(client).ok()?;
+
+                Some(LayoutClient::new(
+                    client,
+                    size_hints.copied().unwrap_or_default(),
+                    *state,
+                    weight.map_or(DEFAULT_CLIENT_WEIGHT, |weight| **weight),
+                    selected == Some(client),
+                ))
+            })
+            .collect::<Vec<LayoutClient>>();
+
+        let Some(tag) = tags.current_mut() else {
+            continue;
+        };
+
+        let layout = tag.layout().clone();
+        let transform = tag.transform();
+        let area = transform.area(*monitor_geometry);
+        layout.engine().prepare(area, &tiled, tag);
+
+        let tab_style = layout.engine().tabs();
+        let tabs = tiled
+            .iter()
+            .filter_map(|tiled| {
+                let (_, _, _, _, name, ..) = clients.get(tiled.entity()).ok()?;
+                let scheme = if tiled.focused() {
+                    ColorScheme::Selected
+                } else {
+                    ColorScheme::Normal
+                };
+
+                Some((tiled.entity(), (**name).clone(), scheme))
+            })
+            .collect::<Vec<(Entity, String, ColorScheme)>>();
+
+        let cells = layout.engine().arrange(area, &tiled, tag);
+
+        // off-screen placement does not pass through the transform, which could mirror it onto
+        // another monitor
+        for tiled in tiled
+            .iter()
+            .filter(|tiled| cells.iter().all(|(client, _)| *client != tiled.entity()))
+        {
+            let Ok((mut geometry, _, frame, ..)) = clients.get_mut(tiled.entity()) else {
+                continue;
+            };
+
+            RWMP::move_offscreen(*geometry, **frame, &conn);
+            *geometry = geometry.offscreen();
+        }
+
+        for (client, cell) in cells {
+            let cell = transform.apply(*monitor_geometry, cell);
+            let Ok((mut geometry, window, frame, ..)) = clients.get_mut(client) else {
+                continue;
+            };
+
+            let Some(tab_style) = tab_style else {
+                *geometry = cell.inset(border_width, border_width, border_width, border_width);
+                RWMP::update_bordered_client_geometry(&config, *geometry, **window, **frame, &conn);
+                commands.entity(client).remove::<TabBar>();
+                continue;
+            };
+
+            let bar_height = tab_style.bar_height(tabs.len(), tab_height);
+            *geometry = cell.inset(
+                border_width,
+                border_width,
+                border_width + bar_height,
+                border_width,
+            );
+
+            RWMP::update_tabbed_client_geometry(
+                &config, *geometry, bar_height, **window, **frame, &conn,
+            );
+            RWMP::draw_tabs(
+                &config,
+                **frame,
+                cell.width(),
+                &tabs
+                    .iter()
+                    .map(|(_, name, scheme)| (name.clone(), *scheme))
+                    .collect::<Vec<(String, ColorScheme)>>(),
+                tab_style,
+                &conn,
+            );
+
+            commands.entity(client).insert(TabBar::new(
+                tabs.iter().map(|(client, ..)| *client).collect(),
+                tab_style,
+                cell.width(),
+            ));
+        }
+
+        // stacked clients are raised whatever is configured, as only the topmost one is visible
+        if let Some(client) = selected
+            && (config.focus().raise() || layout.engine().stacks_clients())
+            && tiled.iter().any(|tiled| tiled.entity() == client)
+            && let Ok((_, _, frame, ..)) = clients.get(client)
+        {
+            RWMP::raise(**frame, &conn);
+        }
+
+        let symbol = layout
+            .engine()
+            .symbol(&tiled, tag)
+            .unwrap_or_else(|| layout.symbol().to_string());
+
+        // the root window only holds one symbol, which other tags may have replaced since
+        if published_symbol.as_ref() != Some(&symbol) {
+            RWMP::set_layout_symbol(&symbol, **root_window, &conn);
+            *published_symbol = Some(symbol.clone());
+        }
+        tag.change_symbol(symbol);
+    }
+}
+
+pub fn handle_set_layout(
+    mut events: EventReader<KeybindTriggered>,
+    mut monitors: Query<(Entity, &mut Tags), With<Monitor>>,
+    layouts: Res<Layouts>,
+) {
+    for event in events.read() {
+        let KeybindAction::SetLayout(name) = event.action() else {
+            continue;
+        };
+
+        let Some(layout) = layouts.get(name) else {
+            warn!("unknown layout: {name}");
+            continue;
+        };
+
+        for (monitor, mut tags) in &mut monitors {
+            if event.monitor() != Some(monitor) {
+                continue;
+            }
+
+            if let Some(tag) = tags.current_mut() {
+                tag.change_layout(layout.clone());
+            }
+        }
+    }
+}
+
+pub fn handle_adjust_gaps(
+    mut events: EventReader<KeybindTriggered>,
+    mut monitors: Query<(Entity, &mut Tags), With<Monitor>>,
+) {
+    for event in events.read() {
+        let KeybindAction::AdjustGaps(delta) = event.action() else {
+            continue;
+        };
+
+        for (monitor, mut tags) in &mut monitors {
+            if event.monitor() != Some(monitor) {
+                continue;
+            }
+
+            if let Some(tag) = tags.current_mut() {
+                let gaps = tag.gaps().adjusted(*delta);
+                tag.change_gaps(gaps);
+            }
+        }
+    }
+}
+
+pub fn handle_client_weight(
+    mut events: EventReader<KeybindTriggered>,
+    mut clients: Query<&mut ClientWeight, With<Client>>,
+    mut monitors: Query<&mut Tags, With<Monitor>>,
+) {
+    for event in events.read() {
+        let weight = match event.action() {
+            KeybindAction::AdjustClientWeight(percent) => Some(*percent as f32 / 100.0),
+            KeybindAction::ResetClientWeight => None,
+            _ => continue,
+        };
+
+        let Some(client) = event.client() else {
+            continue;
+        };
+
+        let Ok(mut client_weight) = clients.get_mut(client) else {
+            continue;
+        };
+
+        **client_weight = weight.map_or(DEFAULT_CLIENT_WEIGHT, |delta| {
+            (**client_weight + delta).clamp(MIN_CLIENT_WEIGHT, MAX_CLIENT_WEIGHT)
+        });
+
+        // weights are not part of the tags, so arrange the monitor of the client explicitly
+        for mut tags in &mut monitors {
+            if tags.contains_client(client) {
+                tags.set_changed();
+            }
+        }
+    }
+}
+
+pub fn handle_layout_transform(
+    mut events: EventReader<KeybindTriggered>,
+    mut monitors: Query<(Entity, &mut Tags), With<Monitor>>,
+) {
+    for event in events.read() {
+        if !matches!(
+            event.action(),
+            KeybindAction::MirrorLayout
+                | KeybindAction::FlipLayout
+                | KeybindAction::RotateLayout
+                | KeybindAction::ResetLayoutTransform
+        ) {
+            continue;
+        }
+
+        for (monitor, mut tags) in &mut monitors {
+            if event.monitor() != Some(monitor) {
+                continue;
+            }
+
+            let Some(tag) = tags.current_mut() else {
+                continue;
+            };
+
+            let mut transform = tag.transform();
+            match event.action() {
+                KeybindAction::MirrorLayout => {
+                    transform.toggle_mirrored();
+                }
+                KeybindAction::FlipLayout => {
+                    transform.toggle_flipped();
+                }
+                KeybindAction::RotateLayout => {
+                    transform.toggle_rotated();
+                }
+                _ => transform = LayoutTransform::default(),
+            }
+
+            tag.change_transform(transform);
+        }
+    }
+}
+
+/// Resizes the current layout of the given [`tag`] on a monitor with the given [`area`] by dragging
+/// the given [`edge`] of the given [`client`] to the given position.
+pub fn resize_layout(
+    tag: &mut Tag,
+    area: Geometry,
+    tiled: &[Entity],
+    client: Entity,
+    edge: Direction,
+    x: i32,
+    y: i32,
+) {
+    let layout = tag.layout().clone();
+    let transform = tag.transform();
+    let (x, y) = transform.point(area, x, y);
+
+    layout.engine().resize(
+        transform.area(area),
+        tiled,
+        tag,
+        client,
+        transform.direction(edge),
+        x,
+        y,
+    );
+}
+
+/// The entities of the given tiled [`clients`], the clients the layout state of the given [`tag`]
+/// has to keep and the focused client, which stateful layouts sync their state with before
+/// arranging.
+pub fn layout_state_clients(
+    clients: &[LayoutClient],
+    tag: &Tag,
+) -> (Vec<Entity>, Vec<Entity>, Option<Entity>) {
+    let tiled = clients
+        .iter()
+        .map(LayoutClient::entity)
+        .collect::<Vec<Entity>>();
+    // clients of other viewed tags are arranged together with the clients of this tag
+    let mut kept = tag.clients().clone();
+    kept.extend(&tiled);
+    let focused = clients
+        .iter()
+        .find(|client| client.focused())
+        .map(LayoutClient::entity);
+
+    (tiled, kept, focused)
+}
+
+/// The client in the given [`direction`] of the given [`client`] as it is arranged by the current
+/// layout of the given [`tag`], if the layout knows better than the geometries on the screen.
+pub fn layout_neighbour(
+    tag: &Tag,
+    tiled: &[Entity],
+    client: Entity,
+    direction: Direction,
+) -> Option<Entity> {
+    let direction = tag.transform().direction(direction);
+
+    tag.layout()
+        .engine()
+        .neighbour(tiled, tag, client, direction)
+}
+
+/// Builders shared by the tests of the layouts.
+#[cfg(test)]
+pub mod testing {
+    use crate::prelude::*;
+
+    /// Spawns the given [`count`] of empty entities.
+    pub fn entities(count: usize) -> Vec<Entity> {
+        let mut world = World::new();
+
+        (0..count).map(|_| world.spawn_empty().id()).collect()
+    }
+
+    /// Creates the given [`count`] of tiled layout clients with the given [`size_hints`].
+    pub fn clients_with_hints(count: usize, size_hints: SizeHints) -> Vec<LayoutClient> {
+        entities(count)
+            .into_iter()
+            .map(|entity| {
+                LayoutClient::new(
+                    entity,
+                    size_hints,
+                    ClientState::default(),
+                    DEFAULT_CLIENT_WEIGHT,
+                    false,
+                )
+            })
+            .collect()
+    }
+
+    /// Creates the given [`count`] of tiled layout clients without size hints.
+    pub fn clients(count: usize) -> Vec<LayoutClient> {
+        clients_with_hints(count, SizeHints::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// All combinations of mirroring, flipping and rotating.
+    fn transforms() -> Vec<LayoutTransform> {
+        (0..8)
+            .map(|bits| {
+                let mut transform = LayoutTransform::default();
+                if bits & 1 != 0 {
+                    transform.toggle_mirrored();
+                }
+                if bits & 2 != 0 {
+                    transform.toggle_flipped();
+                }
+                if bits & 4 != 0 {
+                    transform.toggle_rotated();
+                }
+                transform
+            })
+            .collect()
+    }
+
+    #[test]
+    fn toggling_a_transform_twice_resets_it() {
+        let mut transform = LayoutTransform::default();
+        transform
+            .toggle_mirrored()
+            .toggle_flipped()
+            .toggle_rotated()
+            .toggle_mirrored()
+            .toggle_flipped()
+            .toggle_rotated();
+
+        assert_eq!(transform, LayoutTransform::default());
+    }
+
+    #[test]
+    fn mirroring_and_flipping_twice_maps_cells_back() {
+        let area = Geometry::new(100, 50, 200, 100);
+        let cell = Geometry::new(110, 60, 40, 30);
+
+        for transform in transforms().into_iter().filter(|t| !t.rotated()) {
+            let applied = transform.apply(area, cell);
+            assert_eq!(transform.apply(area, applied), cell, "{transform:?}");
+        }
+    }
+
+    #[test]
+    fn transformed_cells_stay_in_the_area_and_map_back() {
+        let area = Geometry::new(100, 50, 200, 100);
+
+        for transform in transforms() {
+            let layout_area = transform.area(area);
+            let cell = Geometry::new(layout_area.x() + 10, layout_area.y() + 20, 30, 40);
+            let applied = transform.apply(area, cell);
+
+            assert!(applied.x() >= area.x() && applied.right() <= area.right());
+            assert!(applied.y() >= area.y() && applied.bottom() <= area.bottom());
+
+            let (x, y) = applied.center();
+            let (x, y) = transform.point(area, x, y);
+            assert!(cell.contains(x, y), "{transform:?}: {cell:?} ({x}, {y})");
+        }
+    }
+
+    #[test]
+    fn directions_follow_the_transform() {
+        let mut mirrored = LayoutTransform::default();
+        mirrored.toggle_mirrored();
+        assert_eq!(mirrored.direction(Direction::Left), Direction::Right);
+        assert_eq!(mirrored.direction(Direction::Up), Direction::Up);
+
+        let mut rotated = LayoutTransform::default();
+        rotated.toggle_rotated();
+        assert_eq!(rotated.direction(Direction::Right), Direction::Down);
+    }
+}
@@ -9,16 +9,23 @@ pub mod client;
 pub mod color;
 pub mod config;
 pub mod cursor;
+pub mod focus;
 pub mod geometry;
+pub mod hint;
 pub mod keybind;
 pub mod layout;
 pub mod mgmt;
 pub mod monitor;
+pub mod scratchpad;
+pub mod sticky;
+pub mod swap;
+pub mod switcher;
 pub mod tag;
 pub mod tagset;
 pub mod tiling;
 pub mod util;
 pub mod window;
+pub mod workspace;
 
 pub mod prelude;
 
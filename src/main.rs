@@ -14,14 +14,72 @@ fn main() {
         .add_plugins(RWMP)
         .init_resource::<Cli>()
         .init_resource::<MainConfig>()
+        .init_resource::<Layouts>()
+        .insert_resource(FocusedClient(None))
+        .insert_resource(RecentClients(vec![]))
+        .insert_resource(PendingScratchpads(vec![]))
+        .init_resource::<Switcher>()
+        .init_resource::<Hints>()
         .add_event::<KeybindTriggered>()
+        .add_event::<ModifierReleased>()
+        .add_event::<KeyTyped>()
         .add_systems(
             Startup,
             (print_config, add_fullscreen_remove_handler).chain(),
         )
         .add_systems(
             Update,
-            (handle_unmanage, handle_fullscreen, handle_fullscreen_add, handle_shell).chain(),
+            (
+                (
+                    handle_unmanage,
+                    handle_fullscreen,
+                    handle_fullscreen_add,
+                    handle_shell,
+                    handle_view_tag,
+                    handle_client_tags,
+                    handle_workspace_actions,
+                    handle_toggle_scratchpad,
+                    hide_scratchpads,
+                    handle_toggle_sticky,
+                )
+                    .chain(),
+                (
+                    handle_set_layout,
+                    handle_adjust_gaps,
+                    handle_master_actions,
+                    handle_client_weight,
+                    handle_layout_transform,
+                )
+                    .chain(),
+                (
+                    handle_focus_cycle,
+                    handle_focus_direction,
+                    handle_swap_actions,
+                    handle_switcher,
+                    handle_hint_mode,
+                    handle_hint_keys,
+                    handle_tree_actions,
+                    handle_scroll_actions,
+                )
+                    .chain(),
+            )
+                .chain(),
+        )
+        .add_systems(
+            PostUpdate,
+            (
+                remove_abandoned_workspaces,
+                restore_focus,
+                track_focus,
+                update_focus_grabs,
+                track_recent_clients,
+                arrange,
+                announce_workspaces,
+                publish_sticky_state,
+                draw_switcher,
+                draw_hints,
+            )
+                .chain(),
         )
         .run();
 }
@@ -4,26 +4,64 @@ use crate::prelude::*;
 pub struct Unmanaged;
 
 pub fn handle_unmanage(
-    mut monitors: Query<&mut Tags, With<Monitor>>,
+    mut monitors: Query<
+        (Entity, &mut Tags, &mut FocusStack, Option<&SelectedClient>),
+        With<Monitor>,
+    >,
     mut commands: Commands,
     conn: Res<PlatformConnection>,
-    clients: Query<(Entity, &ClientWindow, &Geometry, Option<&ClientFrame>), With<Unmanaged>>,
+    clients: Query<
+        (
+            Entity,
+            &ClientWindow,
+            &Geometry,
+            Option<&ClientFrame>,
+            Has<Scratchpad>,
+        ),
+        With<Unmanaged>,
+    >,
+    mut focused: ResMut<FocusedClient>,
     root_window: Res<MainRootWindow>,
 ) {
-    for (client, window, geometry, frame) in clients {
-        for mut tags in &mut monitors {
-            let tag = tags.get_mut(0).unwrap(); // TODO: tagging
+    for (client, window, geometry, frame, scratchpad) in clients {
+        // the next client to focus is chosen from the focus stack by restore_focus, which only
+        // focuses it on the monitor owning the focused client
+        if **focused == Some(client) {
+            *focused = FocusedClient(None);
+        }
+
+        for (monitor, _, mut stack, selected) in &mut monitors {
+            stack.remove_client(client);
 
-            RWMP::unmanage(
-                client,
-                **window,
-                *geometry,
-                frame.map(|f| **f),
-                **root_window,
-                tag,
-                &mut commands,
-                &conn,
-            );
+            if selected.is_some_and(|selected| **selected == client) {
+                commands.entity(monitor).remove::<SelectedClient>();
+            }
         }
+
+        // a client is only managed on one monitor, but may be part of several of its tags.
+        // scratchpads are not part of any tag, so any monitor can unmanage them
+        let Some(monitor) = monitors
+            .iter()
+            .find(|(_, tags, ..)| tags.contains_client(client))
+            .or_else(|| monitors.iter().next().filter(|_| scratchpad))
+            .map(|(monitor, ..)| monitor)
+        else {
+            continue;
+        };
+
+        let Ok((_, mut tags, ..)) = monitors.get_mut(monitor) else {
+            continue;
+        };
+
+        RWMP::unmanage(
+            client,
+            **window,
+            *geometry,
+            frame.map(|f| **f),
+            **root_window,
+            &mut tags,
+            &mut commands,
+            &conn,
+        );
     }
 }
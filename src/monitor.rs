@@ -2,6 +2,7 @@ use crate::prelude::*;
 
 wrapper!(MonitorId(u8));
 wrapper!(SelectedTagset(Tagset));
+wrapper!(PreviousTagset(Tagset));
 wrapper!(SelectedClient(Entity));
 wrapper!(Tags(Vec<Tag>));
 
@@ -9,3 +10,168 @@ wrapper!(Tags(Vec<Tag>));
 /// of tags, which can each hold clients.
 #[derive(Clone, Copy, Debug, Component)]
 pub struct Monitor;
+
+impl Tags {
+    /// Whether any tag of this monitor contains the given [`client`].
+    pub fn contains_client(&self, client: Entity) -> bool {
+        self.iter().any(|tag| tag.clients().contains(&client))
+    }
+
+    /// Removes the given [`client`] from all tags of this monitor.
+    pub fn remove_client(&mut self, client: Entity) {
+        for tag in self.iter_mut() {
+            tag.clients_mut().retain(|other| *other != client);
+        }
+    }
+
+    /// Swaps the places of the given clients in every tag of this monitor. A tag holding only one of
+    /// them gets the other one instead, so the clients also trade their tags.
+    pub fn swap_clients(&mut self, first: Entity, second: Entity) {
+        for tag in self.iter_mut() {
+            let has_first = tag.clients().contains(&first);
+            let has_second = tag.clients().contains(&second);
+
+            match (has_first, has_second) {
+                (true, true) => tag.swap_clients(first, second),
+                (true, false) => tag.replace_client(first, second),
+                (false, true) => tag.replace_client(second, first),
+                (false, false) => {}
+            }
+        }
+    }
+
+    /// Replaces the [`old`] client with the [`new`] client in every tag that contains it.
+    pub fn replace_client(&mut self, old: Entity, new: Entity) {
+        for tag in self.iter_mut() {
+            if tag.clients().contains(&old) {
+                tag.replace_client(old, new);
+            }
+        }
+    }
+
+    /// Marks every tag as occupied if it holds any clients, and as empty otherwise.
+    pub fn update_occupied(&mut self) {
+        for tag in self.iter_mut() {
+            let occupied = !tag.clients().is_empty();
+            tag.change_occupied(occupied);
+        }
+    }
+
+    /// The clients of all tags, without duplicates.
+    pub fn all_clients(&self) -> Vec<Entity> {
+        unique_clients(self.iter())
+    }
+
+    /// The clients of all selected tags, without duplicates.
+    pub fn visible_clients(&self) -> Vec<Entity> {
+        unique_clients(self.iter().filter(|tag| tag.state().selected()))
+    }
+
+    /// The clients of all selected tags, followed by the clients of other tags that are
+    /// [`sticky`], without duplicates.
+    pub fn shown_clients(&self, sticky: impl Fn(Entity) -> bool) -> Vec<Entity> {
+        let mut clients = self.visible_clients();
+        let sticky_clients = self
+            .all_clients()
+            .into_iter()
+            .filter(|client| !clients.contains(client) && sticky(*client))
+            .collect::<Vec<Entity>>();
+
+        clients.extend(sticky_clients);
+        clients
+    }
+
+    /// The [`shown_clients`](Tags::shown_clients), telling sticky clients apart by the [`state`]
+    /// of each client.
+    pub fn shown_with_sticky<'a>(
+        &self,
+        state: impl Fn(Entity) -> Option<&'a ClientState>,
+    ) -> Vec<Entity> {
+        self.shown_clients(|client| state(client).is_some_and(ClientState::sticky))
+    }
+
+    /// The [`shown_clients`](Tags::shown_clients) that are [`tiled`], in the order they are
+    /// arranged in by the layout.
+    pub fn tiled_clients(
+        &self,
+        sticky: impl Fn(Entity) -> bool,
+        tiled: impl Fn(Entity) -> bool,
+    ) -> Vec<Entity> {
+        self.shown_clients(sticky)
+            .into_iter()
+            .filter(|client| tiled(*client))
+            .collect()
+    }
+
+    /// The tagset of all tags of this monitor.
+    pub fn tagset(&self) -> Tagset {
+        self.iter().map(Tag::idx).collect()
+    }
+
+    /// The tag with the given [`label`].
+    pub fn with_label(&self, label: &str) -> Option<&Tag> {
+        self.iter().find(|tag| tag.label() == label)
+    }
+
+    /// The lowest index that is not used by any tag yet, if the tag limit is not reached.
+    pub fn free_idx(&self) -> Option<u8> {
+        let tagset = self.tagset();
+
+        (1..=MAX_TAGS as u8).find(|idx| !tagset.activated(*idx))
+    }
+
+    /// Removes all dynamic tags that are empty and not viewed, returning their tagset.
+    pub fn remove_abandoned(&mut self) -> Tagset {
+        let abandoned = self
+            .iter()
+            .filter(|tag| tag.dynamic() && tag.clients().is_empty() && !tag.state().selected())
+            .map(Tag::idx)
+            .collect::<Tagset>();
+
+        if !abandoned.is_empty() {
+            self.retain(|tag| !abandoned.activated(tag.idx()));
+        }
+
+        abandoned
+    }
+
+    /// Selects exactly the tags that are activated in the given [`tagset`].
+    pub fn view(&mut self, tagset: &Tagset) {
+        for tag in self.iter_mut() {
+            let selected = tagset.activated(tag.idx());
+            tag.change_selected(selected);
+        }
+    }
+
+    /// The currently viewed tag of this monitor. If several tags are viewed, this is the first one.
+    pub fn current(&self) -> Option<&Tag> {
+        self.iter()
+            .find(|tag| tag.state().selected())
+            .or_else(|| self.first())
+    }
+
+    /// A mutable reference to the currently viewed tag of this monitor.
+    pub fn current_mut(&mut self) -> Option<&mut Tag> {
+        let idx = self
+            .iter()
+            .position(|tag| tag.state().selected())
+            .unwrap_or(0);
+        self.get_mut(idx)
+    }
+}
+
+/// The clients of the given [`tags`] in order, keeping only the first occurrence of clients that
+/// are part of several tags.
+fn unique_clients<'a>(tags: impl Iterator<Item = &'a Tag>) -> Vec<Entity> {
+    let mut clients = vec![];
+
+    for tag in tags {
+        for client in tag.clients() {
+            if !clients.contains(client) {
+                clients.push(*client);
+            }
+        }
+    }
+
+    clients
+}
@@ -28,14 +28,14 @@ pub trait RWMPlatform: Plugin + Clone + Copy {
         conn: &Self::Connection,
     ) -> Result<(Entity, ClientFrame)>;
 
-    /// Unmanages a window.
+    /// Unmanages a window and removes its client from all of the given [`tags`].
     fn unmanage(
         client: Entity,
         window: Window,
         geometry: Geometry,
         frame: Option<Window>,
         root_window: Window,
-        tag: &mut Tag,
+        tags: &mut Tags,
         commands: &mut Commands,
         conn: &Self::Connection,
     );
@@ -49,6 +49,27 @@ pub trait RWMPlatform: Plugin + Clone + Copy {
         conn: &Self::Connection,
     );
 
+    /// Updates the position of the given [`client`], adds the border and makes room for a tab bar
+    /// of the given [`tab_height`] above it.
+    fn update_tabbed_client_geometry(
+        config: &MainConfig,
+        geometry: Geometry,
+        tab_height: u32,
+        window: Window,
+        frame: Window,
+        conn: &Self::Connection,
+    );
+
+    /// Draws the given [`tabs`] at the top of the given [`frame`] of the given [`width`].
+    fn draw_tabs(
+        config: &MainConfig,
+        frame: Window,
+        width: u32,
+        tabs: &[(String, ColorScheme)],
+        style: TabStyle,
+        conn: &Self::Connection,
+    );
+
     /// Deletes the frame window of a client.
     fn delete_frame(
         geometry: Geometry,
@@ -67,12 +88,57 @@ pub trait RWMPlatform: Plugin + Clone + Copy {
         conn: &Self::Connection,
     ) -> Result<ClientFrame>;
 
+    /// Moves the given top-level [`window`] with the given [`geometry`] off-screen, hiding it
+    /// without unmapping it.
+    fn move_offscreen(geometry: Geometry, window: Window, conn: &Self::Connection);
+
     /// Updates the position of the given [`client`] and adds the border.
     fn update_client_geometry(geometry: Geometry, window: Window, conn: &Self::Connection);
 
+    /// Changes the cursor shown while the mouse is grabbed.
+    fn set_grab_cursor(cursor: Cursor, conn: &Self::Connection);
+
     /// Ungrabs the mouse.
     fn ungrab_mouse(state: &Self::Connection);
 
     /// Focuses the given [`window`].
     fn focus(window: Window, conn: &Self::Connection);
+
+    /// Raises the given [`frame`] above all other windows.
+    fn raise(frame: Window, conn: &Self::Connection);
+
+    /// Grabs clicks on the given [`window`] so that they can focus it before they are passed on to
+    /// the window, or releases that grab if [`grab`] is false.
+    fn grab_focus_clicks(window: Window, grab: bool, conn: &Self::Connection);
+
+    /// Asks the given [`window`] to close.
+    fn close(window: Window, conn: &Self::Connection);
+
+    /// Creates a popup window with the given [`geometry`] that is shown above all other windows.
+    fn create_popup(
+        geometry: Geometry,
+        root_window: Window,
+        conn: &Self::Connection,
+    ) -> Result<Window>;
+
+    /// Destroys the given [`popup`] window.
+    fn destroy_popup(popup: Window, conn: &Self::Connection);
+
+    /// Grabs the keyboard, so that all key events are reported until it is ungrabbed again.
+    fn grab_keyboard(root_window: Window, conn: &Self::Connection);
+
+    /// Ungrabs the keyboard.
+    fn ungrab_keyboard(conn: &Self::Connection);
+
+    /// Whether the modifiers of the `switch_client` bindings are currently held.
+    fn modifiers_held(root_window: Window, conn: &Self::Connection) -> bool;
+
+    /// Publishes the current layout [`symbol`] for status bars.
+    fn set_layout_symbol(symbol: &str, root_window: Window, conn: &Self::Connection);
+
+    /// Publishes whether the given [`window`] is visible on all tags.
+    fn set_sticky(window: Window, sticky: bool, conn: &Self::Connection);
+
+    /// Publishes the given desktop [`names`] and the index of the [`current`] desktop for pagers.
+    fn set_desktops(names: &[String], current: usize, root_window: Window, conn: &Self::Connection);
 }
@@ -5,18 +5,34 @@ pub use crate::client::*;
 pub use crate::color::*;
 pub use crate::config::*;
 pub use crate::cursor::*;
+pub use crate::focus::*;
 pub use crate::geometry::*;
+pub use crate::hint::*;
 pub use crate::keybind::*;
 pub use crate::layout::*;
 pub use crate::mgmt::*;
 pub use crate::monitor::*;
 pub use crate::platform::*;
+pub use crate::scratchpad::*;
+pub use crate::sticky::*;
+pub use crate::swap::*;
+pub use crate::switcher::*;
 pub use crate::tag::*;
 pub use crate::tagset::*;
+pub use crate::tiling::bottom_stack::*;
+pub use crate::tiling::centered_master::*;
+pub use crate::tiling::fibonacci::*;
 pub use crate::tiling::fullscreen::*;
+pub use crate::tiling::grid::*;
+pub use crate::tiling::master_stack::*;
+pub use crate::tiling::monocle::*;
+pub use crate::tiling::scrolling::*;
+pub use crate::tiling::tabbed::*;
+pub use crate::tiling::tree::*;
 pub use crate::tiling::*;
 pub use crate::util::*;
 pub use crate::window::*;
+pub use crate::workspace::*;
 
 // X11 impls
 #[cfg(feature = "x11")]
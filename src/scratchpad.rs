@@ -0,0 +1,144 @@
+use std::{
+    process::Command,
+    time::{Duration, Instant},
+};
+
+use crate::prelude::*;
+
+wrapper!(Scratchpad(String));
+wrapper!(PendingScratchpads(Vec<(String, Instant)>));
+
+/// How long a spawned scratchpad may take to map its window before toggling it spawns it again.
+pub const SCRATCHPAD_SPAWN_TIMEOUT: Duration = Duration::from_secs(10);
+
+impl PendingScratchpads {
+    /// Whether the scratchpad with the given [`name`] was spawned recently and has no window yet.
+    pub fn is_pending(&self, name: &str) -> bool {
+        self.iter()
+            .any(|(other, spawned)| other == name && spawned.elapsed() < SCRATCHPAD_SPAWN_TIMEOUT)
+    }
+
+    /// Records that the scratchpad with the given [`name`] was just spawned.
+    pub fn spawned(&mut self, name: &str) {
+        self.claimed(name);
+        self.push((name.to_string(), Instant::now()));
+    }
+
+    /// Records that the scratchpad with the given [`name`] mapped its window.
+    pub fn claimed(&mut self, name: &str) {
+        self.retain(|(other, _)| other != name);
+    }
+}
+
+/// Moves the given scratchpad client to its [`geometry`] and focuses it above all other windows.
+pub fn show_scratchpad(
+    config: &MainConfig,
+    geometry: Geometry,
+    window: Window,
+    frame: Window,
+    conn: &PlatformConnection,
+) {
+    RWMP::update_bordered_client_geometry(config, geometry, window, frame, conn);
+    RWMP::raise(frame, conn);
+    RWMP::focus(window, conn);
+}
+
+pub fn handle_toggle_scratchpad(
+    mut events: EventReader<KeybindTriggered>,
+    mut commands: Commands,
+    mut scratchpads: Query<
+        (
+            Entity,
+            &Scratchpad,
+            &mut Geometry,
+            &ClientWindow,
+            &ClientFrame,
+            Has<Hidden>,
+        ),
+        With<Client>,
+    >,
+    monitors: Query<(&Tags, &Geometry), (With<Monitor>, Without<Client>)>,
+    mut pending: ResMut<PendingScratchpads>,
+    conn: Res<PlatformConnection>,
+    config: Res<MainConfig>,
+) {
+    for event in events.read() {
+        let KeybindAction::ToggleScratchpad(name) = event.action() else {
+            continue;
+        };
+
+        let Some(scratchpad) = config.scratchpad(name) else {
+            warn!("cannot toggle unknown scratchpad {name}");
+            continue;
+        };
+
+        // scratchpads are shown on the monitor of the selected client
+        let Some(monitor) = monitors
+            .iter()
+            .find(|(tags, _)| {
+                event
+                    .client()
+                    .is_some_and(|client| tags.contains_client(client))
+            })
+            .or_else(|| monitors.iter().next())
+            .map(|(_, geometry)| *geometry)
+        else {
+            continue;
+        };
+
+        let Some((client, _, mut geometry, window, frame, hidden)) = scratchpads
+            .iter_mut()
+            .find(|(_, other, ..)| other.0 == *name)
+        else {
+            // the window becomes the scratchpad once it is mapped
+            if pending.is_pending(name) {
+                continue;
+            }
+
+            match Command::new("sh")
+                .arg("-c")
+                .arg(scratchpad.command())
+                .spawn()
+            {
+                Ok(_) => pending.spawned(name),
+                Err(e) => error!("failed spawning scratchpad {name}: {e}"),
+            }
+            continue;
+        };
+
+        let (x, y) = geometry.center();
+        if !hidden && monitor.contains(x, y) {
+            RWMP::move_offscreen(*geometry, **frame, &conn);
+            commands.entity(client).insert(Hidden);
+            continue;
+        }
+
+        *geometry = scratchpad.geometry(monitor);
+        show_scratchpad(&config, *geometry, **window, **frame, &conn);
+        commands.entity(client).remove::<Hidden>();
+    }
+}
+
+/// Hides the shown scratchpads of every monitor whose viewed tagset changed, as scratchpads do not
+/// belong to any tag.
+pub fn hide_scratchpads(
+    mut commands: Commands,
+    scratchpads: Query<
+        (Entity, &Geometry, &ClientFrame),
+        (With<Client>, With<Scratchpad>, Without<Hidden>),
+    >,
+    monitors: Query<&Geometry, (With<Monitor>, Changed<SelectedTagset>, Without<Client>)>,
+    conn: Res<PlatformConnection>,
+) {
+    for monitor in &monitors {
+        for (client, geometry, frame) in &scratchpads {
+            let (x, y) = geometry.center();
+            if !monitor.contains(x, y) {
+                continue;
+            }
+
+            RWMP::move_offscreen(*geometry, **frame, &conn);
+            commands.entity(client).insert(Hidden);
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use crate::prelude::*;
+
+pub fn handle_toggle_sticky(
+    mut events: EventReader<KeybindTriggered>,
+    mut clients: Query<&mut ClientState, With<Client>>,
+    mut monitors: Query<&mut Tags, With<Monitor>>,
+) {
+    for event in events.read() {
+        if event.action() != &KeybindAction::ToggleSticky {
+            continue;
+        }
+
+        let Some(client) = event.client() else {
+            continue;
+        };
+
+        let Ok(mut state) = clients.get_mut(client) else {
+            continue;
+        };
+
+        let sticky = !state.sticky();
+        state.change_sticky(sticky);
+        relayout_client(client, &mut monitors);
+    }
+}
+
+/// Arranges the monitor of the given [`client`] again, e.g. because it became sticky.
+pub fn relayout_client(client: Entity, monitors: &mut Query<&mut Tags, With<Monitor>>) {
+    for mut tags in monitors {
+        if tags.contains_client(client) {
+            tags.set_changed();
+        }
+    }
+}
+
+/// Publishes the sticky state of clients whenever their state changes.
+pub fn publish_sticky_state(
+    clients: Query<(&ClientWindow, &ClientState), (With<Client>, Changed<ClientState>)>,
+    conn: Res<PlatformConnection>,
+) {
+    for (window, state) in clients {
+        RWMP::set_sticky(**window, state.sticky(), &conn);
+    }
+}
@@ -0,0 +1,162 @@
+use crate::prelude::*;
+
+pub fn handle_swap_actions(
+    mut events: EventReader<KeybindTriggered>,
+    mut commands: Commands,
+    mut monitors: Query<
+        (Entity, &mut Tags, &Geometry, Option<&mut SelectedClient>),
+        (With<Monitor>, Without<Client>),
+    >,
+    clients: Query<
+        (&Geometry, &ClientWindow, &ClientFrame, &ClientState),
+        (With<Client>, Without<Fullscreened>, Without<Monitor>),
+    >,
+    conn: Res<PlatformConnection>,
+) {
+    for event in events.read() {
+        if !matches!(
+            event.action(),
+            KeybindAction::SwapNext
+                | KeybindAction::SwapPrevious
+                | KeybindAction::SwapDirection(_)
+                | KeybindAction::Zoom
+        ) {
+            continue;
+        }
+
+        let Some(client) = event.client() else {
+            continue;
+        };
+
+        let sticky = |client| {
+            clients
+                .get(client)
+                .is_ok_and(|(_, _, _, state)| state.sticky())
+        };
+        // only tiled clients have a position in the layout to swap
+        let tiled = |client| {
+            clients
+                .get(client)
+                .ok()
+                .filter(|(_, _, _, state)| !state.floating())
+                .map(|(geometry, ..)| *geometry)
+        };
+
+        if tiled(client).is_none() {
+            continue;
+        }
+
+        if let KeybindAction::SwapDirection(direction) = event.action() {
+            let screen = on_screen_clients(
+                monitors
+                    .iter()
+                    .map(|(monitor, tags, geometry, _)| (monitor, tags, geometry)),
+                sticky,
+                tiled,
+            );
+            let Some((_, target)) = layout_target(
+                monitors.iter().map(|(monitor, tags, ..)| (monitor, tags)),
+                client,
+                *direction,
+                sticky,
+                |client| tiled(client).is_some(),
+            )
+            .or_else(|| directional_target(client, *direction, &screen)) else {
+                continue;
+            };
+
+            if let Ok((_, window, frame, _)) = clients.get(client) {
+                swap_clients(
+                    client,
+                    target,
+                    **window,
+                    **frame,
+                    &mut monitors,
+                    &mut commands,
+                    &conn,
+                );
+            }
+            continue;
+        }
+
+        for (_, mut tags, ..) in &mut monitors {
+            if !tags.contains_client(client) {
+                continue;
+            }
+
+            let order = tags.tiled_clients(sticky, |client| tiled(client).is_some());
+
+            let Some(idx) = order.iter().position(|other| *other == client) else {
+                continue;
+            };
+
+            let target = match event.action() {
+                KeybindAction::SwapNext => order[(idx + 1) % order.len()],
+                KeybindAction::SwapPrevious => order[(idx + order.len() - 1) % order.len()],
+                // zooming the master swaps it with the first client of the stack instead
+                _ if idx == 0 => {
+                    let Some(target) = order.get(1) else {
+                        continue;
+                    };
+                    *target
+                }
+                _ => order[0],
+            };
+
+            if target == client {
+                continue;
+            }
+
+            tags.swap_clients(client, target);
+        }
+    }
+}
+
+/// Swaps the given [`client`] with the given [`target`], which may be on another monitor. The
+/// client stays focused when it moves to the monitor of the target.
+pub fn swap_clients(
+    client: Entity,
+    target: Entity,
+    window: Window,
+    frame: Window,
+    monitors: &mut Query<
+        (Entity, &mut Tags, &Geometry, Option<&mut SelectedClient>),
+        (With<Monitor>, Without<Client>),
+    >,
+    commands: &mut Commands,
+    conn: &PlatformConnection,
+) {
+    let monitor_of = |client| {
+        monitors
+            .iter()
+            .find(|(_, tags, ..)| tags.contains_client(client))
+            .map(|(monitor, ..)| monitor)
+    };
+
+    let (Some(source), Some(destination)) = (monitor_of(client), monitor_of(target)) else {
+        return;
+    };
+
+    if source == destination {
+        if let Ok((_, mut tags, ..)) = monitors.get_mut(source) {
+            tags.swap_clients(client, target);
+        }
+        return;
+    }
+
+    // the clients trade places, keeping their positions in the layouts
+    if let Ok((_, mut tags, _, selected)) = monitors.get_mut(source) {
+        tags.replace_client(client, target);
+
+        // focus moves along with the client, so the source monitor must not take it back
+        if let Some(mut selected) = selected {
+            *selected.bypass_change_detection() = SelectedClient(target);
+        }
+    }
+
+    if let Ok((_, mut tags, ..)) = monitors.get_mut(destination) {
+        tags.replace_client(target, client);
+    }
+
+    focus_client(destination, client, window, frame, commands, conn);
+}
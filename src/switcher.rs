@@ -0,0 +1,232 @@
+use crate::prelude::*;
+
+wrapper!(RecentClients(Vec<Entity>));
+
+/// Sent when a modifier key is released, focusing the client chosen in the [`Switcher`].
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ModifierReleased;
+
+/// The window switcher, which cycles through all clients in most-recently-used order while its
+/// modifier is held.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq, Getters)]
+pub struct Switcher {
+    /// The popup listing the clients, if the switcher is open.
+    #[getter(copy)]
+    popup: Option<Window>,
+
+    /// The width of the popup.
+    width: u32,
+
+    /// The clients to switch between, most recently focused first.
+    clients: Vec<Entity>,
+
+    /// The index of the client that is focused once the modifier is released.
+    idx: usize,
+}
+
+/// Moves every newly focused client to the front of the [`RecentClients`].
+pub fn track_recent_clients(
+    mut recent: ResMut<RecentClients>,
+    focused: Res<FocusedClient>,
+    clients: Query<(), With<Client>>,
+) {
+    if !focused.is_changed() {
+        return;
+    }
+
+    let Some(focused) = **focused else {
+        return;
+    };
+
+    let mut order = vec![focused];
+    order.extend(
+        recent
+            .iter()
+            .copied()
+            .filter(|client| *client != focused && clients.contains(*client)),
+    );
+    *recent = RecentClients(order);
+}
+
+pub fn handle_switcher(
+    mut events: EventReader<KeybindTriggered>,
+    mut released: EventReader<ModifierReleased>,
+    mut commands: Commands,
+    mut switcher: ResMut<Switcher>,
+    mut monitors: Query<
+        (
+            Entity,
+            &mut Tags,
+            &Geometry,
+            &mut SelectedTagset,
+            &mut PreviousTagset,
+        ),
+        (With<Monitor>, Without<Client>),
+    >,
+    clients: Query<(Entity, &ClientWindow, &ClientFrame, &ClientState), With<Client>>,
+    recent: Res<RecentClients>,
+    conn: Res<PlatformConnection>,
+    config: Res<MainConfig>,
+    root_window: Res<MainRootWindow>,
+) {
+    let mut commit = released.read().count() > 0;
+
+    for event in events.read() {
+        let KeybindAction::SwitchClient(offset) = event.action() else {
+            continue;
+        };
+
+        if switcher.popup.is_some() {
+            let idx = (switcher.idx as i32 + offset).rem_euclid(switcher.clients.len() as i32);
+            switcher.idx = idx as usize;
+            continue;
+        }
+
+        // clients that were never focused come last, clients outside of any tag are left out
+        let mut candidates = recent.to_vec();
+        candidates.extend(clients.iter().map(|(client, ..)| client));
+        let mut switchable = vec![];
+        for client in candidates {
+            if !switchable.contains(&client)
+                && monitors
+                    .iter()
+                    .any(|(_, tags, ..)| tags.contains_client(client))
+            {
+                switchable.push(client);
+            }
+        }
+
+        if switchable.is_empty() {
+            continue;
+        }
+
+        let Some(monitor) = monitors
+            .iter()
+            .find(|(_, tags, ..)| {
+                event
+                    .client()
+                    .is_some_and(|client| tags.contains_client(client))
+            })
+            .or_else(|| monitors.iter().next())
+            .map(|(_, _, geometry, ..)| *geometry)
+        else {
+            continue;
+        };
+
+        let width = monitor.width() / 3;
+        let height = switchable.len() as u32 * config.tabs().height() as u32;
+        let (x, y) = monitor.center();
+        let geometry = Geometry::new(x - width as i32 / 2, y - height as i32 / 2, width, height);
+
+        let popup = match RWMP::create_popup(geometry, **root_window, &conn) {
+            Ok(popup) => popup,
+            Err(e) => {
+                error!("failed opening the window switcher: {e}");
+                continue;
+            }
+        };
+
+        RWMP::grab_keyboard(**root_window, &conn);
+        let idx = offset.rem_euclid(switchable.len() as i32) as usize;
+        *switcher = Switcher {
+            popup: Some(popup),
+            width,
+            clients: switchable,
+            idx,
+        };
+
+        // the modifier may have been released before the keyboard was grabbed
+        commit |= !RWMP::modifiers_held(**root_window, &conn);
+    }
+
+    if !commit {
+        return;
+    }
+
+    let Some(popup) = switcher.popup else {
+        return;
+    };
+
+    RWMP::destroy_popup(popup, &conn);
+    RWMP::ungrab_keyboard(&conn);
+
+    let client = switcher.clients.get(switcher.idx).copied();
+    *switcher = Switcher::default();
+
+    let Some((client, window, frame, _)) = client.and_then(|client| clients.get(client).ok())
+    else {
+        return;
+    };
+
+    let Some((monitor, mut tags, _, mut selected_tagset, mut previous_tagset)) = monitors
+        .iter_mut()
+        .find(|(_, tags, ..)| tags.contains_client(client))
+    else {
+        return;
+    };
+
+    let shown = tags.shown_with_sticky(|client| clients.get(client).ok().map(|(.., state)| state));
+
+    if !shown.contains(&client)
+        && let Some(tag) = tags.iter().find(|tag| tag.clients().contains(&client))
+    {
+        view_tagset(
+            Tagset::single(tag.idx()),
+            &mut tags,
+            &mut selected_tagset,
+            &mut previous_tagset,
+        );
+    }
+
+    focus_client(monitor, client, **window, **frame, &mut commands, &conn);
+}
+
+/// Draws the clients of the open [`Switcher`] with their tag labels, highlighting the chosen one.
+pub fn draw_switcher(
+    switcher: Res<Switcher>,
+    clients: Query<&ClientName, With<Client>>,
+    monitors: Query<&Tags, With<Monitor>>,
+    conn: Res<PlatformConnection>,
+    config: Res<MainConfig>,
+) {
+    if !switcher.is_changed() {
+        return;
+    }
+
+    let Some(popup) = switcher.popup else {
+        return;
+    };
+
+    let entries = switcher
+        .clients
+        .iter()
+        .enumerate()
+        .map(|(idx, client)| {
+            let name = clients
+                .get(*client)
+                .map_or_else(|_| String::new(), |name| (**name).clone());
+            let labels = monitors
+                .iter()
+                .flat_map(|tags| tags.iter())
+                .filter(|tag| tag.clients().contains(client))
+                .map(|tag| tag.label().clone())
+                .collect::<Vec<String>>();
+            let scheme = if idx == switcher.idx {
+                ColorScheme::Selected
+            } else {
+                ColorScheme::Normal
+            };
+
+            (format!("{name} [{}]", labels.join(", ")), scheme)
+        })
+        .collect::<Vec<(String, ColorScheme)>>();
+
+    RWMP::draw_tabs(
+        &config,
+        popup,
+        switcher.width,
+        &entries,
+        TabStyle::Stacked,
+        &conn,
+    );
+}
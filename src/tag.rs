@@ -1,5 +1,11 @@
 use crate::prelude::*;
 
+/// The smallest percentage of the size that the master area can use.
+pub const MIN_MASTER_FACTOR: f32 = 0.05;
+
+/// The largest percentage of the size that the master area can use.
+pub const MAX_MASTER_FACTOR: f32 = 0.95;
+
 /// A tag is a workspace that contains any number of clients. By default, only one tag is focused, but any amount of tags can be selected.
 #[derive(Debug, Clone, Getters)]
 #[constructor(named(new), fields(idx, label, layout))]
@@ -16,14 +22,35 @@ pub struct Tag {
     /// The current layout of the tag.
     layout: Layout,
 
-    /// The percentage of the size that the master client is using.
+    /// The symbol of the current layout, as computed when the tag was last arranged.
+    symbol: String,
+
+    /// The split tree of the clients in this tag, used by tree layouts.
+    tree: SplitTree,
+
+    /// The strip of columns of the clients in this tag, used by scrolling layouts.
+    scroll: ScrollState,
+
+    /// The percentage of the size that the master area is using.
     master_factor: f32,
 
+    /// The amount of clients in the master area.
+    master_count: usize,
+
     /// Gaps between windows.
+    #[getter(copy)]
     gaps: Gaps,
 
+    /// The transform applied to the current layout.
+    #[getter(copy)]
+    transform: LayoutTransform,
+
     /// The state of this tag.
     state: TagState,
+
+    /// Whether this tag was created at runtime. Dynamic tags are removed once they are empty and
+    /// no longer viewed.
+    dynamic: bool,
 }
 
 impl PartialEq for Tag {
@@ -35,16 +62,129 @@ impl PartialEq for Tag {
 impl Eq for Tag {}
 
 impl Tag {
+    /// Creates the tag with the given [`idx`] and [`label`], using the layout, master area and
+    /// gaps configured for it.
+    pub fn configured(idx: u8, label: String, config: &MainConfig, layouts: &Layouts) -> Tag {
+        let tags_cfg = config.tags();
+        let definition = tags_cfg.definition(idx);
+
+        let layout = layouts.get(tags_cfg.layout(idx)).unwrap_or_else(|| {
+            warn!(
+                "unknown layout {} for tag {idx}, using the default layout",
+                tags_cfg.layout(idx)
+            );
+            layouts.default_layout()
+        });
+
+        let gaps = definition
+            .and_then(|definition| definition.gaps().as_ref())
+            .unwrap_or(config.gaps());
+
+        let mut tag = Tag::new(idx, label, layout);
+        tag.change_master_factor(tags_cfg.master_factor(idx))
+            .change_master_count(tags_cfg.default_master_count() as usize)
+            .change_gaps(gaps.into());
+        tag
+    }
+
     /// A mutable reference to the clients of this tag.
     pub fn clients_mut(&mut self) -> &mut Vec<Entity> {
         &mut self.clients
     }
 
+    /// A mutable reference to the split tree of this tag.
+    pub fn tree_mut(&mut self) -> &mut SplitTree {
+        &mut self.tree
+    }
+
+    /// A mutable reference to the strip of columns of this tag.
+    pub fn scroll_mut(&mut self) -> &mut ScrollState {
+        &mut self.scroll
+    }
+
+    /// Replaces the [`old`] client with the [`new`] client, keeping its position in this tag.
+    pub fn replace_client(&mut self, old: Entity, new: Entity) {
+        self.clients.retain(|client| *client != new);
+
+        match self.clients.iter().position(|client| *client == old) {
+            Some(idx) => self.clients[idx] = new,
+            None => self.clients.push(new),
+        }
+
+        self.tree.replace(old, new);
+    }
+
+    /// Swaps the positions of the given clients in this tag.
+    pub fn swap_clients(&mut self, first: Entity, second: Entity) {
+        let first_idx = self.clients.iter().position(|client| *client == first);
+        let second_idx = self.clients.iter().position(|client| *client == second);
+
+        if let (Some(first_idx), Some(second_idx)) = (first_idx, second_idx) {
+            self.clients.swap(first_idx, second_idx);
+            self.tree.swap(first, second);
+            self.scroll.swap(first, second);
+        }
+    }
+
     /// Changes the layout of this tag.
     pub fn change_layout(&mut self, layout: Layout) -> &mut Tag {
         self.layout = layout;
         self
     }
+
+    /// Changes the current layout symbol of this tag.
+    pub fn change_symbol(&mut self, symbol: String) -> &mut Tag {
+        self.symbol = symbol;
+        self
+    }
+
+    /// Changes the master factor of this tag.
+    pub fn change_master_factor(&mut self, master_factor: f32) -> &mut Tag {
+        self.master_factor = master_factor;
+        self
+    }
+
+    /// Changes the amount of clients in the master area of this tag.
+    pub fn change_master_count(&mut self, master_count: usize) -> &mut Tag {
+        self.master_count = master_count;
+        self
+    }
+
+    /// Selects or deselects this tag.
+    pub fn change_selected(&mut self, selected: bool) -> &mut Tag {
+        self.state.selected = selected;
+        self
+    }
+
+    /// Changes the label of this tag.
+    pub fn change_label(&mut self, label: String) -> &mut Tag {
+        self.label = label;
+        self
+    }
+
+    /// Marks this tag as created at runtime or configured.
+    pub fn change_dynamic(&mut self, dynamic: bool) -> &mut Tag {
+        self.dynamic = dynamic;
+        self
+    }
+
+    /// Marks this tag as occupied or empty.
+    pub fn change_occupied(&mut self, occupied: bool) -> &mut Tag {
+        self.state.occupied = occupied;
+        self
+    }
+
+    /// Changes the gaps of this tag.
+    pub fn change_gaps(&mut self, gaps: Gaps) -> &mut Tag {
+        self.gaps = gaps;
+        self
+    }
+
+    /// Changes the transform of the layout of this tag.
+    pub fn change_transform(&mut self, transform: LayoutTransform) -> &mut Tag {
+        self.transform = transform;
+        self
+    }
 }
 
 /// Gaps between windows.
@@ -56,11 +196,56 @@ pub struct Gaps {
     /// Vertical gaps between windows.
     inner_vertical: u32,
 
-    /// Horizontal outer gaps to the root window.
-    outer_horizontal: u32,
+    /// Outer gap to the left edge of the monitor.
+    outer_left: u32,
+
+    /// Outer gap to the right edge of the monitor.
+    outer_right: u32,
+
+    /// Outer gap to the top edge of the monitor.
+    outer_top: u32,
+
+    /// Outer gap to the bottom edge of the monitor.
+    outer_bottom: u32,
+}
 
-    /// Vertical outer gaps to the root window.
-    outer_vertical: u32,
+impl Gaps {
+    /// Shrinks the given [`area`] by the outer gaps.
+    pub fn apply_outer(&self, area: Geometry) -> Geometry {
+        area.inset(
+            self.outer_left,
+            self.outer_right,
+            self.outer_top,
+            self.outer_bottom,
+        )
+    }
+
+    /// Grows all gaps by the given [`delta`], without shrinking them below zero.
+    pub fn adjusted(&self, delta: i32) -> Gaps {
+        let adjust = |gap: u32| gap.saturating_add_signed(delta);
+
+        Gaps {
+            inner_horizontal: adjust(self.inner_horizontal),
+            inner_vertical: adjust(self.inner_vertical),
+            outer_left: adjust(self.outer_left),
+            outer_right: adjust(self.outer_right),
+            outer_top: adjust(self.outer_top),
+            outer_bottom: adjust(self.outer_bottom),
+        }
+    }
+}
+
+impl From<&GapsConfig> for Gaps {
+    fn from(config: &GapsConfig) -> Self {
+        Self {
+            inner_horizontal: config.inner_left() + config.inner_right(),
+            inner_vertical: config.inner_top() + config.inner_bottom(),
+            outer_left: config.outer_left(),
+            outer_right: config.outer_right(),
+            outer_top: config.outer_top(),
+            outer_bottom: config.outer_bottom(),
+        }
+    }
 }
 
 /// The state of a tag.
@@ -1,38 +1,276 @@
 use crate::prelude::*;
 
 /// The maximum amount of tags a monitor can have.
-pub const MAX_TAGS: usize = 10;
+pub const MAX_TAGS: usize = u32::BITS as usize;
 
-/// Represents a set of tags that can be toggled between their indices and zeroes.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Component)]
-pub struct Tagset([u8; MAX_TAGS]);
+/// Represents a set of tags as a bitmask. The bit of a tag is the tag minus one, so that tag `1`
+/// uses the lowest bit.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Component)]
+pub struct Tagset(u32);
 
-impl Default for Tagset {
-    fn default() -> Self {
-        Tagset(zeroed::<MAX_TAGS>())
+impl Tagset {
+    /// A tagset without any activated tags.
+    pub const fn empty() -> Self {
+        Tagset(0)
     }
-}
 
-impl Tagset {
-    /// All tags in this tagset, regardless of their status.
-    pub const fn tags(&self) -> [u8; MAX_TAGS] {
+    /// A tagset with only the given [`tag`] activated.
+    pub const fn single(tag: u8) -> Self {
+        Tagset(Self::bit(tag))
+    }
+
+    /// The bit of the given [`tag`]. Tag `0` and tags above [`MAX_TAGS`] do not exist and have no
+    /// bit.
+    const fn bit(tag: u8) -> u32 {
+        if tag == 0 || tag as usize > MAX_TAGS {
+            return 0;
+        }
+
+        1 << (tag - 1)
+    }
+
+    /// The raw bitmask of this tagset.
+    pub const fn bits(&self) -> u32 {
         self.0
     }
 
-    /// Whether the given tag is activated and set to itself.
+    /// Whether the given tag is activated.
     pub const fn activated(&self, tag: u8) -> bool {
-        self.0[tag as usize] == tag
+        self.0 & Self::bit(tag) != 0
     }
 
     /// Activates the given tag.
     pub const fn activate(&mut self, tag: u8) -> &mut Self {
-        self.0[tag as usize] = tag;
+        self.0 |= Self::bit(tag);
         self
     }
 
     /// Deactivates the given tag.
     pub const fn deactivate(&mut self, tag: u8) -> &mut Self {
-        self.0[tag as usize] = 0;
+        self.0 &= !Self::bit(tag);
         self
     }
+
+    /// Activates the given tag if it is deactivated, deactivates it otherwise.
+    pub const fn toggle(&mut self, tag: u8) -> &mut Self {
+        self.0 ^= Self::bit(tag);
+        self
+    }
+
+    /// Whether no tag is activated.
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// All tags that are activated in this or the [`other`] tagset.
+    pub const fn union(&self, other: Tagset) -> Tagset {
+        Tagset(self.0 | other.0)
+    }
+
+    /// All tags that are activated in both this and the [`other`] tagset.
+    pub const fn intersection(&self, other: Tagset) -> Tagset {
+        Tagset(self.0 & other.0)
+    }
+
+    /// All tags that are activated in this tagset, but not in the [`other`] one.
+    pub const fn difference(&self, other: Tagset) -> Tagset {
+        Tagset(self.0 & !other.0)
+    }
+
+    /// All activated tags, in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = u8> {
+        let bits = self.0;
+
+        (1..=MAX_TAGS as u8).filter(move |tag| bits & Self::bit(*tag) != 0)
+    }
+}
+
+impl FromIterator<u8> for Tagset {
+    fn from_iter<T: IntoIterator<Item = u8>>(tags: T) -> Self {
+        let mut tagset = Tagset::empty();
+        for tag in tags {
+            tagset.activate(tag);
+        }
+        tagset
+    }
+}
+
+pub fn handle_view_tag(
+    mut events: EventReader<KeybindTriggered>,
+    mut monitors: Query<
+        (Entity, &mut Tags, &mut SelectedTagset, &mut PreviousTagset),
+        With<Monitor>,
+    >,
+) {
+    for event in events.read() {
+        if !matches!(
+            event.action(),
+            KeybindAction::ViewTag(_)
+                | KeybindAction::ToggleViewTag(_)
+                | KeybindAction::ViewPreviousTagset
+        ) {
+            continue;
+        }
+
+        for (monitor, mut tags, mut selected_tagset, mut previous_tagset) in &mut monitors {
+            if event.monitor() != Some(monitor) {
+                continue;
+            }
+
+            let tagset = match event.action() {
+                KeybindAction::ViewTag(tag) => Tagset::single(*tag),
+                KeybindAction::ToggleViewTag(tag) => {
+                    let mut tagset = **selected_tagset;
+                    tagset.toggle(*tag);
+                    tagset
+                }
+                _ => **previous_tagset,
+            };
+
+            // at least one enabled tag must stay visible
+            if tagset.intersection(tags.tagset()).is_empty() {
+                continue;
+            }
+
+            view_tagset(
+                tagset,
+                &mut tags,
+                &mut selected_tagset,
+                &mut previous_tagset,
+            );
+        }
+    }
+}
+
+/// Views the given [`tagset`], remembering the currently selected tagset as the previous one.
+pub fn view_tagset(
+    tagset: Tagset,
+    tags: &mut Tags,
+    selected_tagset: &mut SelectedTagset,
+    previous_tagset: &mut PreviousTagset,
+) {
+    if tagset == **selected_tagset {
+        return;
+    }
+
+    **previous_tagset = **selected_tagset;
+    **selected_tagset = tagset;
+    tags.view(&tagset);
+}
+
+pub fn handle_client_tags(
+    mut events: EventReader<KeybindTriggered>,
+    mut monitors: Query<(&mut Tags, &mut SelectedTagset, &mut PreviousTagset), With<Monitor>>,
+) {
+    for event in events.read() {
+        let (KeybindAction::SendToTag(idx)
+        | KeybindAction::FollowToTag(idx)
+        | KeybindAction::ToggleClientTag(idx)) = *event.action()
+        else {
+            continue;
+        };
+
+        let Some(client) = event.client() else {
+            continue;
+        };
+
+        for (mut tags, mut selected_tagset, mut previous_tagset) in &mut monitors {
+            if !tags.contains_client(client) {
+                continue;
+            }
+
+            let Some(target) = tags.iter().position(|tag| tag.idx() == idx) else {
+                continue;
+            };
+
+            match event.action() {
+                KeybindAction::ToggleClientTag(_) => {
+                    let tagged = tags
+                        .iter()
+                        .filter(|tag| tag.clients().contains(&client))
+                        .count();
+
+                    if !tags[target].clients().contains(&client) {
+                        tags[target].clients_mut().push(client);
+                    } else if tagged > 1 {
+                        // a client always has to stay part of at least one tag
+                        tags[target].clients_mut().retain(|other| *other != client);
+                    }
+                }
+                _ => {
+                    let moved = tags
+                        .iter()
+                        .all(|tag| (tag.idx() == idx) == tag.clients().contains(&client));
+
+                    if !moved {
+                        tags.remove_client(client);
+                        tags[target].clients_mut().push(client);
+                    }
+                }
+            }
+
+            if let KeybindAction::FollowToTag(_) = event.action() {
+                view_tagset(
+                    Tagset::single(idx),
+                    &mut tags,
+                    &mut selected_tagset,
+                    &mut previous_tagset,
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tags_use_the_bit_below_them() {
+        assert_eq!(Tagset::single(1).bits(), 1);
+        assert_eq!(Tagset::single(3).bits(), 0b100);
+        assert_eq!(Tagset::single(MAX_TAGS as u8).bits(), 1 << 31);
+    }
+
+    #[test]
+    fn tag_zero_is_never_activated() {
+        assert!(Tagset::single(0).is_empty());
+
+        let mut tagset = Tagset::single(1);
+        tagset.activate(0).toggle(0);
+        assert_eq!(tagset, Tagset::single(1));
+        assert!(!tagset.activated(0));
+    }
+
+    #[test]
+    fn tags_can_be_activated_deactivated_and_toggled() {
+        let mut tagset = Tagset::empty();
+        tagset
+            .activate(2)
+            .activate(5)
+            .toggle(3)
+            .toggle(5)
+            .deactivate(2);
+
+        assert_eq!(tagset.iter().collect::<Vec<u8>>(), vec![3]);
+        assert!(tagset.activated(3));
+        assert!(!tagset.activated(5));
+    }
+
+    #[test]
+    fn tagsets_can_be_combined() {
+        let first = [1, 2, 3].into_iter().collect::<Tagset>();
+        let second = [3, 4].into_iter().collect::<Tagset>();
+
+        assert_eq!(
+            first.union(second).iter().collect::<Vec<u8>>(),
+            vec![1, 2, 3, 4]
+        );
+        assert_eq!(first.intersection(second), Tagset::single(3));
+        assert_eq!(
+            first.difference(second).iter().collect::<Vec<u8>>(),
+            vec![1, 2]
+        );
+        assert!(first.intersection(Tagset::empty()).is_empty());
+    }
 }
@@ -0,0 +1,57 @@
+use crate::prelude::*;
+
+/// The first [`Tag::master_count`] clients are placed next to each other in the master area at the
+/// top, which uses [`Tag::master_factor`] of the height, all other clients are placed next to each
+/// other along the bottom.
+pub struct BottomStack;
+
+impl LayoutEngine for BottomStack {
+    fn arrange(
+        &self,
+        area: Geometry,
+        clients: &[LayoutClient],
+        tag: &Tag,
+    ) -> Vec<(Entity, Geometry)> {
+        let gaps = tag.gaps();
+        let area = gaps.apply_outer(area);
+
+        let (masters, stack) = clients.split_at(tag.master_count().min(clients.len()));
+        let (master_area, stack_area) = match (masters.is_empty(), stack.is_empty()) {
+            (true, true) => return vec![],
+            (false, true) | (true, false) => (area, area),
+            (false, false) => area.split(false, tag.master_factor(), gaps.inner_vertical()),
+        };
+
+        let master_cells = master_area
+            .split_columns_weighted(&LayoutClient::weights(masters), gaps.inner_horizontal());
+        let stack_cells = stack_area
+            .split_columns_weighted(&LayoutClient::weights(stack), gaps.inner_horizontal());
+
+        masters
+            .iter()
+            .chain(stack)
+            .map(LayoutClient::entity)
+            .zip(master_cells.into_iter().chain(stack_cells))
+            .collect()
+    }
+
+    fn symbol(&self, _clients: &[LayoutClient], tag: &Tag) -> Option<String> {
+        Some(master_symbol("TTT", tag))
+    }
+
+    fn resize(
+        &self,
+        area: Geometry,
+        tiled: &[Entity],
+        tag: &mut Tag,
+        client: Entity,
+        edge: Direction,
+        _x: i32,
+        y: i32,
+    ) {
+        if is_master_edge(tiled, tag, client, edge, Direction::Down) {
+            let area = tag.gaps().apply_outer(area);
+            resize_master(tag, y - area.y(), area.height());
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use crate::prelude::*;
+
+/// The first [`Tag::master_count`] clients are stacked in the centered master area, which uses
+/// [`Tag::master_factor`] of the width, the other clients are stacked on both sides of it,
+/// starting on the right. With a single stack client, this behaves like [`MasterStack`].
+pub struct CenteredMaster;
+
+impl LayoutEngine for CenteredMaster {
+    fn arrange(
+        &self,
+        area: Geometry,
+        clients: &[LayoutClient],
+        tag: &Tag,
+    ) -> Vec<(Entity, Geometry)> {
+        let master_count = tag.master_count().min(clients.len());
+        if master_count == 0 || clients.len() <= master_count + 1 {
+            return MasterStack.arrange(area, clients, tag);
+        }
+
+        let gaps = tag.gaps();
+        let area = gaps.apply_outer(area);
+
+        let (masters, stack) = clients.split_at(master_count);
+
+        let gap = gaps.inner_horizontal();
+        let master_factor = tag.master_factor();
+        let master_width = (area.width().saturating_sub(2 * gap) as f32 * master_factor) as u32;
+        let side_width = area.width().saturating_sub(2 * gap + master_width);
+        let left_width = side_width / 2;
+
+        let left_area = Geometry::new(area.x(), area.y(), left_width, area.height());
+        let master_area = Geometry::new(
+            left_area.right() + gap as i32,
+            area.y(),
+            master_width,
+            area.height(),
+        );
+        let right_x = master_area.right() + gap as i32;
+        let right_area = Geometry::new(
+            right_x,
+            area.y(),
+            (area.right() - right_x).max(0) as u32,
+            area.height(),
+        );
+
+        let (right, left): (Vec<_>, Vec<_>) = stack
+            .iter()
+            .copied()
+            .enumerate()
+            .partition(|(idx, _)| idx % 2 == 0);
+
+        let master_cells =
+            master_area.split_rows_weighted(&LayoutClient::weights(masters), gaps.inner_vertical());
+        let mut geometries = masters
+            .iter()
+            .map(LayoutClient::entity)
+            .zip(master_cells)
+            .collect::<Vec<(Entity, Geometry)>>();
+        for (side, side_area) in [(right, right_area), (left, left_area)] {
+            let side = side
+                .into_iter()
+                .map(|(_, client)| client)
+                .collect::<Vec<LayoutClient>>();
+            let rows =
+                side_area.split_rows_weighted(&LayoutClient::weights(&side), gaps.inner_vertical());
+            geometries.extend(side.iter().map(LayoutClient::entity).zip(rows));
+        }
+
+        geometries
+    }
+
+    fn symbol(&self, _clients: &[LayoutClient], tag: &Tag) -> Option<String> {
+        Some(master_symbol("|M|", tag))
+    }
+
+    fn resize(
+        &self,
+        area: Geometry,
+        tiled: &[Entity],
+        tag: &mut Tag,
+        client: Entity,
+        edge: Direction,
+        x: i32,
+        y: i32,
+    ) {
+        let master_count = tag.master_count().min(tiled.len());
+        if master_count == 0 || tiled.len() <= master_count + 1 {
+            return MasterStack.resize(area, tiled, tag, client, edge, x, y);
+        }
+
+        if !matches!(edge, Direction::Left | Direction::Right) {
+            return;
+        }
+
+        // the master area grows in both directions from the center
+        let area = tag.gaps().apply_outer(area);
+        let (center, _) = area.center();
+        resize_master(tag, 2 * (x - center).abs(), area.width());
+    }
+}
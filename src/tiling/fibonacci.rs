@@ -0,0 +1,156 @@
+use crate::prelude::*;
+
+/// Each client splits the remaining space in half, alternating between vertical and horizontal
+/// splits, with the clients spiraling inwards.
+pub struct Spiral;
+
+impl LayoutEngine for Spiral {
+    fn arrange(
+        &self,
+        area: Geometry,
+        clients: &[LayoutClient],
+        tag: &Tag,
+    ) -> Vec<(Entity, Geometry)> {
+        fibonacci(area, clients, tag, true)
+    }
+}
+
+/// Each client splits the remaining space in half, alternating between vertical and horizontal
+/// splits, with the clients dwindling towards the bottom right corner.
+pub struct Dwindle;
+
+impl LayoutEngine for Dwindle {
+    fn arrange(
+        &self,
+        area: Geometry,
+        clients: &[LayoutClient],
+        tag: &Tag,
+    ) -> Vec<(Entity, Geometry)> {
+        fibonacci(area, clients, tag, false)
+    }
+}
+
+/// Arranges the given [`clients`] in a spiral or dwindle. The first split uses the master factor of
+/// the given [`tag`]. Once a split would make a client smaller than its minimum size, all remaining
+/// clients are stacked in the remaining space.
+fn fibonacci(
+    area: Geometry,
+    clients: &[LayoutClient],
+    tag: &Tag,
+    spiral: bool,
+) -> Vec<(Entity, Geometry)> {
+    let gaps = tag.gaps();
+    let mut remaining = gaps.apply_outer(area);
+    let mut geometries = Vec::with_capacity(clients.len());
+
+    for (idx, client) in clients.iter().enumerate() {
+        let rest = &clients[idx + 1..];
+        if rest.is_empty() {
+            geometries.push((client.entity(), remaining));
+            break;
+        }
+
+        let vertical = idx % 2 == 0;
+        let factor = if idx == 0 { tag.master_factor() } else { 0.5 };
+
+        let gap = if vertical {
+            gaps.inner_horizontal()
+        } else {
+            gaps.inner_vertical()
+        };
+
+        let (first, second) = remaining.split(vertical, factor, gap);
+        let (own, next) = if spiral && idx % 4 >= 2 {
+            (second, first)
+        } else {
+            (first, second)
+        };
+
+        if !fits(own, std::slice::from_ref(client)) || !fits(next, rest) {
+            let stack = &clients[idx..];
+            let cells = if vertical {
+                remaining.split_rows(stack.len(), gaps.inner_vertical())
+            } else {
+                remaining.split_columns(stack.len(), gaps.inner_horizontal())
+            };
+
+            geometries.extend(stack.iter().map(LayoutClient::entity).zip(cells));
+            break;
+        }
+
+        geometries.push((client.entity(), own));
+        remaining = next;
+    }
+
+    geometries
+}
+
+/// Whether all given [`clients`] can be at least as large as their minimum size when they share
+/// the given [`area`].
+fn fits(area: Geometry, clients: &[LayoutClient]) -> bool {
+    clients.iter().all(|client| {
+        let min = client.size_hints().min();
+        area.width() >= min.width() && area.height() >= min.height()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::testing::{clients, clients_with_hints};
+
+    fn min_size(width: u32, height: u32) -> SizeHints {
+        SizeHints::new(
+            SizeDimensionHint::default(),
+            SizeDimensionHint::default(),
+            SizeDimensionHint::default(),
+            SizeDimensionHint::new(width, height),
+            SizeConstraintHint::default(),
+        )
+    }
+
+    fn arrange(engine: impl LayoutEngine, clients: &[LayoutClient]) -> Vec<Geometry> {
+        let mut tag = Tag::new(1, "1".to_string(), Layout::new("[@]", "spiral", Spiral));
+        tag.change_master_factor(0.5);
+
+        engine
+            .arrange(Geometry::new(0, 0, 300, 300), clients, &tag)
+            .into_iter()
+            .map(|(_, geometry)| geometry)
+            .collect()
+    }
+
+    #[test]
+    fn clients_split_the_remaining_space() {
+        assert_eq!(
+            arrange(Dwindle, &clients(3)),
+            vec![
+                Geometry::new(0, 0, 150, 300),
+                Geometry::new(150, 0, 150, 150),
+                Geometry::new(150, 150, 150, 150),
+            ]
+        );
+    }
+
+    #[test]
+    fn clients_are_stacked_once_the_first_split_is_too_small() {
+        let area = Geometry::new(0, 0, 300, 300);
+
+        assert_eq!(
+            arrange(Spiral, &clients_with_hints(3, min_size(200, 200))),
+            area.split_rows(3, 0)
+        );
+    }
+
+    #[test]
+    fn remaining_clients_are_stacked_once_a_later_split_is_too_small() {
+        assert_eq!(
+            arrange(Dwindle, &clients_with_hints(3, min_size(100, 200))),
+            vec![
+                Geometry::new(0, 0, 150, 300),
+                Geometry::new(150, 0, 75, 300),
+                Geometry::new(225, 0, 75, 300),
+            ]
+        );
+    }
+}
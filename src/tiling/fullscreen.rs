@@ -45,15 +45,25 @@ pub fn add_fullscreen_remove_handler(world: &mut World) {
 
 fn handle_fullscreen_remove(
     removal: On<Remove, Fullscreened>,
-    mut query: Query<(Entity, &mut Geometry, &OriginalGeometry, &ClientWindow)>,
+    mut query: Query<(
+        Entity,
+        &mut Geometry,
+        &OriginalGeometry,
+        &ClientWindow,
+        Option<&ClientWeight>,
+    )>,
     mut dragging: ResMut<Dragging>,
-    mut monitors: Query<&mut Tags, With<Monitor>>,
+    mut monitors: Query<
+        (Entity, &mut Tags, &mut FocusStack, Option<&SelectedClient>),
+        With<Monitor>,
+    >,
+    mut focused: ResMut<FocusedClient>,
     mut commands: Commands,
     conn: Res<PlatformConnection>,
     root_window: Res<MainRootWindow>,
     config: Res<MainConfig>,
 ) {
-    for (client, mut geometry, original_geometry, window) in &mut query {
+    for (client, mut geometry, original_geometry, window, weight) in &mut query {
         if client != removal.target().unwrap() {
             continue;
         }
@@ -64,13 +74,15 @@ fn handle_fullscreen_remove(
 
         commands.entity(client).despawn();
 
-        for mut tags in &mut monitors {
-            let tag = tags.get_mut(0).unwrap(); // TODO: tagging
-            let (client, frame) = match RWMP::manage(
+        for (monitor, mut tags, mut stack, selected) in &mut monitors {
+            let Some(tag) = tags.iter().position(|tag| tag.clients().contains(&client)) else {
+                continue;
+            };
+            let (remanaged, frame) = match RWMP::manage(
                 **window,
                 *geometry,
                 **root_window,
-                tag,
+                &mut tags[tag],
                 &mut commands,
                 &conn,
             ) {
@@ -81,11 +93,27 @@ fn handle_fullscreen_remove(
                 Ok(v) => v,
             };
 
+            // keep the position of the client in all of its tags and their split trees
+            tags.replace_client(client, remanaged);
+
+            // focus stays with the client as well
+            stack.replace_client(client, remanaged);
+            if selected.is_some_and(|selected| **selected == client) {
+                commands.entity(monitor).insert(SelectedClient(remanaged));
+            }
+            if **focused == Some(client) {
+                *focused = FocusedClient(Some(remanaged));
+            }
+
+            if let Some(weight) = weight {
+                commands.entity(remanaged).insert(weight.clone());
+            }
+
             RWMP::update_bordered_client_geometry(
                 &config, *geometry, **window, *frame, &conn,
             );
  
-            commands.entity(client).insert(TransitioningFullscreenStates);
+            commands.entity(remanaged).insert(TransitioningFullscreenStates);
         }
 
         RWMP::focus(**window, &conn);
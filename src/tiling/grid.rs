@@ -0,0 +1,48 @@
+use crate::prelude::*;
+
+/// Arranges all clients in a grid of columns. If the clients cannot be distributed evenly, the
+/// rightmost columns hold one more client.
+pub struct Grid;
+
+impl LayoutEngine for Grid {
+    fn arrange(
+        &self,
+        area: Geometry,
+        clients: &[LayoutClient],
+        tag: &Tag,
+    ) -> Vec<(Entity, Geometry)> {
+        let gaps = tag.gaps();
+        let area = gaps.apply_outer(area);
+
+        if clients.is_empty() {
+            return vec![];
+        }
+
+        let count = clients.len();
+        let column_count = (1..=count)
+            .find(|columns| columns * columns >= count)
+            .unwrap_or(count);
+
+        let mut remaining = clients.iter();
+        let mut geometries = Vec::with_capacity(count);
+
+        for (idx, column) in area
+            .split_columns(column_count, gaps.inner_horizontal())
+            .into_iter()
+            .enumerate()
+        {
+            let rows =
+                count / column_count + usize::from(idx >= column_count - count % column_count);
+
+            geometries.extend(
+                remaining
+                    .by_ref()
+                    .take(rows)
+                    .map(LayoutClient::entity)
+                    .zip(column.split_rows(rows, gaps.inner_vertical())),
+            );
+        }
+
+        geometries
+    }
+}
@@ -0,0 +1,211 @@
+use crate::prelude::*;
+
+/// The dwm-style master/stack layout. The first [`Tag::master_count`] clients are stacked in the
+/// master area on the left, which uses [`Tag::master_factor`] of the width, all other clients are
+/// stacked on the right. Clients share their area according to their weights.
+pub struct MasterStack;
+
+impl LayoutEngine for MasterStack {
+    fn arrange(
+        &self,
+        area: Geometry,
+        clients: &[LayoutClient],
+        tag: &Tag,
+    ) -> Vec<(Entity, Geometry)> {
+        let gaps = tag.gaps();
+        let area = gaps.apply_outer(area);
+
+        let (masters, stack) = clients.split_at(tag.master_count().min(clients.len()));
+        let (master_area, stack_area) = match (masters.is_empty(), stack.is_empty()) {
+            (true, true) => return vec![],
+            (false, true) | (true, false) => (area, area),
+            (false, false) => area.split(true, tag.master_factor(), gaps.inner_horizontal()),
+        };
+
+        let master_cells =
+            master_area.split_rows_weighted(&LayoutClient::weights(masters), gaps.inner_vertical());
+        let stack_cells =
+            stack_area.split_rows_weighted(&LayoutClient::weights(stack), gaps.inner_vertical());
+
+        masters
+            .iter()
+            .chain(stack)
+            .map(LayoutClient::entity)
+            .zip(master_cells.into_iter().chain(stack_cells))
+            .collect()
+    }
+
+    fn symbol(&self, _clients: &[LayoutClient], tag: &Tag) -> Option<String> {
+        Some(master_symbol("[]=", tag))
+    }
+
+    fn resize(
+        &self,
+        area: Geometry,
+        tiled: &[Entity],
+        tag: &mut Tag,
+        client: Entity,
+        edge: Direction,
+        x: i32,
+        _y: i32,
+    ) {
+        if is_master_edge(tiled, tag, client, edge, Direction::Right) {
+            let area = tag.gaps().apply_outer(area);
+            resize_master(tag, x - area.x(), area.width());
+        }
+    }
+}
+
+/// Whether the given [`edge`] of the given [`client`] borders the boundary between the master area
+/// and the stack, with the stack being in the given [`stack_direction`].
+pub fn is_master_edge(
+    tiled: &[Entity],
+    tag: &Tag,
+    client: Entity,
+    edge: Direction,
+    stack_direction: Direction,
+) -> bool {
+    let Some(idx) = tiled.iter().position(|tiled| *tiled == client) else {
+        return false;
+    };
+
+    let master_count = tag.master_count().min(tiled.len());
+    if master_count == 0 || master_count == tiled.len() {
+        return false;
+    }
+
+    if idx < master_count {
+        edge == stack_direction
+    } else {
+        edge == stack_direction.opposite()
+    }
+}
+
+/// Changes the master factor of the given [`tag`] so that the master area is [`size`] of the
+/// [`total`] size.
+pub fn resize_master(tag: &mut Tag, size: i32, total: u32) {
+    if total == 0 {
+        return;
+    }
+
+    let master_factor = size as f32 / total as f32;
+    tag.change_master_factor(master_factor.clamp(MIN_MASTER_FACTOR, MAX_MASTER_FACTOR));
+}
+
+/// The given layout [`symbol`] followed by the master count and master factor of the given [`tag`].
+pub fn master_symbol(symbol: &str, tag: &Tag) -> String {
+    format!(
+        "{symbol} {}:{}%",
+        tag.master_count(),
+        (tag.master_factor() * 100.0).round()
+    )
+}
+
+pub fn handle_master_actions(
+    mut events: EventReader<KeybindTriggered>,
+    mut monitors: Query<(Entity, &mut Tags), With<Monitor>>,
+    config: Res<MainConfig>,
+) {
+    for event in events.read() {
+        if !matches!(
+            event.action(),
+            KeybindAction::AdjustMasterCount(_)
+                | KeybindAction::SetMasterFactor(_)
+                | KeybindAction::AdjustMasterFactor(_)
+                | KeybindAction::ResetMaster
+        ) {
+            continue;
+        }
+
+        for (monitor, mut tags) in &mut monitors {
+            if event.monitor() != Some(monitor) {
+                continue;
+            }
+
+            let Some(tag) = tags.current_mut() else {
+                continue;
+            };
+
+            let (master_count, master_factor) = match event.action() {
+                KeybindAction::AdjustMasterCount(delta) => (
+                    tag.master_count().saturating_add_signed(*delta as isize),
+                    tag.master_factor(),
+                ),
+                KeybindAction::SetMasterFactor(percent) => {
+                    (tag.master_count(), *percent as f32 / 100.0)
+                }
+                KeybindAction::AdjustMasterFactor(percent) => (
+                    tag.master_count(),
+                    tag.master_factor() + *percent as f32 / 100.0,
+                ),
+                KeybindAction::ResetMaster => (
+                    config.tags().default_master_count() as usize,
+                    config.tags().master_factor(tag.idx()),
+                ),
+                _ => continue,
+            };
+
+            // the master count is only limited by the clients when arranging, like in dwm
+            tag.change_master_count(master_count)
+                .change_master_factor(master_factor.clamp(MIN_MASTER_FACTOR, MAX_MASTER_FACTOR));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::testing::clients;
+
+    fn arrange(master_count: usize, clients: &[LayoutClient]) -> Vec<Geometry> {
+        let mut tag = Tag::new(1, "1".to_string(), Layout::new("[]=", "tile", MasterStack));
+        tag.change_master_factor(0.5)
+            .change_master_count(master_count);
+
+        MasterStack
+            .arrange(Geometry::new(0, 0, 300, 300), clients, &tag)
+            .into_iter()
+            .map(|(_, geometry)| geometry)
+            .collect()
+    }
+
+    #[test]
+    fn without_masters_all_clients_are_stacked() {
+        assert_eq!(
+            arrange(0, &clients(3)),
+            vec![
+                Geometry::new(0, 0, 300, 100),
+                Geometry::new(0, 100, 300, 100),
+                Geometry::new(0, 200, 300, 100),
+            ]
+        );
+    }
+
+    #[test]
+    fn one_master_uses_the_master_factor() {
+        assert_eq!(
+            arrange(1, &clients(3)),
+            vec![
+                Geometry::new(0, 0, 150, 300),
+                Geometry::new(150, 0, 150, 150),
+                Geometry::new(150, 150, 150, 150),
+            ]
+        );
+    }
+
+    #[test]
+    fn more_masters_than_clients_use_the_whole_area() {
+        assert_eq!(
+            arrange(5, &clients(2)),
+            vec![
+                Geometry::new(0, 0, 300, 150),
+                Geometry::new(0, 150, 300, 150)
+            ]
+        );
+    }
+
+    #[test]
+    fn nothing_is_arranged_without_clients() {
+        assert!(arrange(1, &[]).is_empty());
+    }
+}
@@ -1 +1,10 @@
+pub mod bottom_stack;
+pub mod centered_master;
+pub mod fibonacci;
 pub mod fullscreen;
+pub mod grid;
+pub mod master_stack;
+pub mod monocle;
+pub mod scrolling;
+pub mod tabbed;
+pub mod tree;
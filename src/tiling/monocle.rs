@@ -0,0 +1,31 @@
+use crate::prelude::*;
+
+/// Gives every client the full monitor area. The selected client is raised above the others, so
+/// the clients are stacked beneath it.
+pub struct Monocle;
+
+impl LayoutEngine for Monocle {
+    fn arrange(
+        &self,
+        area: Geometry,
+        clients: &[LayoutClient],
+        tag: &Tag,
+    ) -> Vec<(Entity, Geometry)> {
+        let area = tag.gaps().apply_outer(area);
+
+        clients
+            .iter()
+            .map(|client| (client.entity(), area))
+            .collect()
+    }
+
+    fn stacks_clients(&self) -> bool {
+        true
+    }
+
+    fn symbol(&self, clients: &[LayoutClient], _tag: &Tag) -> Option<String> {
+        let hidden = clients.len().saturating_sub(1);
+
+        (hidden > 0).then(|| format!("[{hidden}]"))
+    }
+}
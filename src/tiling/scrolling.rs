@@ -0,0 +1,442 @@
+use crate::prelude::*;
+
+/// A column of the [`Scrolling`] layout.
+#[derive(Debug, Clone, PartialEq, Getters)]
+pub struct ScrollColumn {
+    /// The clients in this column, from top to bottom.
+    clients: Vec<Entity>,
+
+    /// The percentage of the monitor width this column is using.
+    width: f32,
+}
+
+/// The horizontal strip of columns of a tag, used by the [`Scrolling`] layout.
+#[derive(Debug, Clone, Default, PartialEq, Getters)]
+pub struct ScrollState {
+    /// All columns, from left to right.
+    columns: Vec<ScrollColumn>,
+
+    /// The offset of the viewport from the start of the strip.
+    offset: i32,
+}
+
+impl ScrollState {
+    /// The index of the column holding the given [`client`].
+    fn column_of(&self, client: Entity) -> Option<usize> {
+        self.columns
+            .iter()
+            .position(|column| column.clients.contains(&client))
+    }
+
+    /// Removes all clients that are not part of the given tag [`clients`] and inserts all [`tiled`]
+    /// clients that are missing as new columns right of the [`focused`] client.
+    pub fn sync(
+        &mut self,
+        clients: &[Entity],
+        tiled: &[Entity],
+        focused: Option<Entity>,
+        width: f32,
+    ) {
+        for column in &mut self.columns {
+            column.clients.retain(|client| clients.contains(client));
+        }
+        self.columns.retain(|column| !column.clients.is_empty());
+
+        let mut idx = focused
+            .and_then(|focused| self.column_of(focused))
+            .map_or(self.columns.len(), |idx| idx + 1);
+
+        for client in tiled {
+            if self.column_of(*client).is_some() {
+                continue;
+            }
+
+            self.columns.insert(
+                idx,
+                ScrollColumn {
+                    clients: vec![*client],
+                    width,
+                },
+            );
+            idx += 1;
+        }
+    }
+
+    /// The start relative to the strip and the width of every column that holds any of the
+    /// [`visible`] clients.
+    fn spans(&self, width: u32, gap: u32, visible: &[Entity]) -> Vec<(usize, i32, u32)> {
+        let mut x = 0;
+
+        self.columns
+            .iter()
+            .enumerate()
+            .filter(|(_, column)| column.clients.iter().any(|client| visible.contains(client)))
+            .map(|(idx, column)| {
+                let column_width = (width as f32 * column.width) as u32;
+                let span = (idx, x, column_width);
+                x += (column_width + gap) as i32;
+                span
+            })
+            .collect()
+    }
+
+    /// Scrolls the viewport of the given [`width`] so that the column of the given [`client`] is
+    /// fully visible.
+    pub fn scroll_to(&mut self, client: Entity, width: u32, gap: u32, visible: &[Entity]) {
+        let spans = self.spans(width, gap, visible);
+        let Some(column) = self.column_of(client) else {
+            return;
+        };
+
+        let Some((_, x, column_width)) = spans.iter().find(|(idx, ..)| *idx == column) else {
+            return;
+        };
+
+        if *x < self.offset {
+            self.offset = *x;
+        } else if x + *column_width as i32 > self.offset + width as i32 {
+            self.offset = x + *column_width as i32 - width as i32;
+        }
+
+        let strip_width = spans
+            .last()
+            .map_or(0, |(_, x, column_width)| x + *column_width as i32);
+        self.offset = self.offset.min(strip_width - width as i32).max(0);
+    }
+
+    /// The client in the column left or right of the given [`client`], keeping its row where the
+    /// column has enough clients. Only columns with [`visible`] clients count.
+    pub fn neighbour(
+        &self,
+        client: Entity,
+        direction: Direction,
+        visible: &[Entity],
+    ) -> Option<Entity> {
+        let columns = self
+            .columns
+            .iter()
+            .map(|column| {
+                column
+                    .clients
+                    .iter()
+                    .copied()
+                    .filter(|client| visible.contains(client))
+                    .collect::<Vec<Entity>>()
+            })
+            .filter(|clients| !clients.is_empty())
+            .collect::<Vec<Vec<Entity>>>();
+
+        let idx = columns
+            .iter()
+            .position(|clients| clients.contains(&client))?;
+        let row = columns[idx].iter().position(|other| *other == client)?;
+
+        let target = match direction {
+            Direction::Left => idx.checked_sub(1)?,
+            Direction::Right => idx + 1,
+            Direction::Up | Direction::Down => return None,
+        };
+
+        let clients = columns.get(target)?;
+        clients.get(row).or(clients.last()).copied()
+    }
+
+    /// Swaps the positions of the given clients.
+    pub fn swap(&mut self, first: Entity, second: Entity) {
+        for client in self
+            .columns
+            .iter_mut()
+            .flat_map(|column| column.clients.iter_mut())
+        {
+            if *client == first {
+                *client = second;
+            } else if *client == second {
+                *client = first;
+            }
+        }
+    }
+
+    /// Grows the column of the given [`client`] by [`delta`] of the monitor width.
+    pub fn adjust_width(&mut self, client: Entity, delta: f32) {
+        if let Some(idx) = self.column_of(client) {
+            let column = &mut self.columns[idx];
+            column.width = (column.width + delta).clamp(0.05, 1.0);
+        }
+    }
+
+    /// Moves the given [`client`] to the bottom of the column on the left.
+    pub fn consume(&mut self, client: Entity) {
+        let Some(idx) = self.column_of(client).filter(|idx| *idx > 0) else {
+            return;
+        };
+
+        self.columns[idx].clients.retain(|other| *other != client);
+        self.columns[idx - 1].clients.push(client);
+        self.columns.retain(|column| !column.clients.is_empty());
+    }
+
+    /// Moves the given [`client`] out of its column into a new column on the right.
+    pub fn expel(&mut self, client: Entity) {
+        let Some(idx) = self.column_of(client) else {
+            return;
+        };
+
+        let column = &mut self.columns[idx];
+        if column.clients.len() < 2 {
+            return;
+        }
+
+        column.clients.retain(|other| *other != client);
+        let width = column.width;
+        self.columns.insert(
+            idx + 1,
+            ScrollColumn {
+                clients: vec![client],
+                width,
+            },
+        );
+    }
+
+    /// Arranges the [`visible`] clients of this strip in the given [`area`]. Clients of columns
+    /// outside of the viewport are left out.
+    pub fn arrange(
+        &self,
+        area: Geometry,
+        gaps: Gaps,
+        visible: &[Entity],
+    ) -> Vec<(Entity, Geometry)> {
+        let area = gaps.apply_outer(area);
+        let mut geometries = vec![];
+
+        for (idx, x, width) in self.spans(area.width(), gaps.inner_horizontal(), visible) {
+            let clients = self.columns[idx]
+                .clients
+                .iter()
+                .copied()
+                .filter(|client| visible.contains(client))
+                .collect::<Vec<Entity>>();
+
+            let column = Geometry::new(area.x() + x - self.offset, area.y(), width, area.height());
+            if column.right() <= area.x() || column.x() >= area.right() {
+                continue;
+            }
+
+            geometries.extend(
+                clients
+                    .iter()
+                    .copied()
+                    .zip(column.split_rows(clients.len(), gaps.inner_vertical())),
+            );
+        }
+
+        geometries
+    }
+}
+
+/// Arranges the clients in an infinite horizontal strip of columns. The viewport scrolls so that
+/// the column of the selected client is fully visible.
+#[constructor(named(new), fields(column_width))]
+pub struct Scrolling {
+    /// The width of new columns as a fraction of the monitor width.
+    column_width: f32,
+}
+
+impl LayoutEngine for Scrolling {
+    fn prepare(&self, area: Geometry, clients: &[LayoutClient], tag: &mut Tag) {
+        let (tiled, kept, focused) = layout_state_clients(clients, tag);
+        let gaps = tag.gaps();
+        let width = gaps.apply_outer(area).width();
+
+        let scroll = tag.scroll_mut();
+        scroll.sync(&kept, &tiled, focused, self.column_width);
+
+        if let Some(focused) = focused {
+            scroll.scroll_to(focused, width, gaps.inner_horizontal(), &tiled);
+        }
+    }
+
+    fn arrange(
+        &self,
+        area: Geometry,
+        clients: &[LayoutClient],
+        tag: &Tag,
+    ) -> Vec<(Entity, Geometry)> {
+        let visible = clients
+            .iter()
+            .map(LayoutClient::entity)
+            .collect::<Vec<Entity>>();
+
+        tag.scroll().arrange(area, tag.gaps(), &visible)
+    }
+
+    fn neighbour(
+        &self,
+        tiled: &[Entity],
+        tag: &Tag,
+        client: Entity,
+        direction: Direction,
+    ) -> Option<Entity> {
+        // columns scrolled out of the viewport are not on the screen
+        tag.scroll().neighbour(client, direction, tiled)
+    }
+}
+
+pub fn handle_scroll_actions(
+    mut events: EventReader<KeybindTriggered>,
+    mut monitors: Query<&mut Tags, With<Monitor>>,
+) {
+    for event in events.read() {
+        if !matches!(
+            event.action(),
+            KeybindAction::AdjustColumnWidth(_)
+                | KeybindAction::ConsumeIntoColumn
+                | KeybindAction::ExpelFromColumn
+        ) {
+            continue;
+        }
+
+        let Some(client) = event.client() else {
+            continue;
+        };
+
+        for mut tags in &mut monitors {
+            if !tags.contains_client(client) {
+                continue;
+            }
+
+            let Some(tag) = tags.current_mut() else {
+                continue;
+            };
+
+            let scroll = tag.scroll_mut();
+            match event.action() {
+                KeybindAction::AdjustColumnWidth(percent) => {
+                    scroll.adjust_width(client, *percent as f32 / 100.0)
+                }
+                KeybindAction::ConsumeIntoColumn => scroll.consume(client),
+                KeybindAction::ExpelFromColumn => scroll.expel(client),
+                _ => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::testing::{clients, entities};
+
+    #[test]
+    fn new_columns_use_the_column_width_of_the_layout() {
+        let clients = clients(3);
+        let scrolling = Scrolling::new(0.4);
+        let mut tag = Tag::new(
+            1,
+            "1".to_string(),
+            Layout::new("[S]", "scrolling", TestLayout),
+        );
+        scrolling.prepare(Geometry::new(0, 0, 100, 100), &clients, &mut tag);
+
+        let widths = tag
+            .scroll()
+            .columns()
+            .iter()
+            .map(|column| column.width())
+            .collect::<Vec<f32>>();
+        assert_eq!(widths, vec![0.4, 0.4, 0.4]);
+    }
+
+    #[test]
+    fn scrolling_keeps_the_column_in_the_viewport() {
+        let clients = entities(4);
+        let mut scroll = ScrollState::default();
+        scroll.sync(&clients, &clients, None, 0.5);
+
+        scroll.scroll_to(clients[3], 100, 0, &clients);
+        assert_eq!(scroll.offset(), 100);
+
+        scroll.scroll_to(clients[2], 100, 0, &clients);
+        assert_eq!(scroll.offset(), 100);
+
+        scroll.scroll_to(clients[0], 100, 0, &clients);
+        assert_eq!(scroll.offset(), 0);
+    }
+
+    #[test]
+    fn columns_outside_of_the_viewport_are_left_out() {
+        let clients = entities(3);
+        let mut scroll = ScrollState::default();
+        scroll.sync(&clients, &clients, None, 0.5);
+        scroll.scroll_to(clients[2], 100, 0, &clients);
+
+        let arranged = scroll
+            .arrange(Geometry::new(0, 0, 100, 100), Gaps::default(), &clients)
+            .into_iter()
+            .map(|(client, _)| client)
+            .collect::<Vec<Entity>>();
+        assert_eq!(arranged, vec![clients[1], clients[2]]);
+    }
+
+    #[test]
+    fn scrolling_never_moves_past_the_strip() {
+        let clients = entities(1);
+        let mut scroll = ScrollState::default();
+        scroll.sync(&clients, &clients, None, 0.5);
+
+        scroll.scroll_to(clients[0], 100, 0, &clients);
+        assert_eq!(scroll.offset(), 0);
+    }
+
+    #[test]
+    fn column_widths_are_clamped() {
+        let clients = entities(1);
+        let mut scroll = ScrollState::default();
+        scroll.sync(&clients, &clients, None, 0.5);
+
+        scroll.adjust_width(clients[0], 5.0);
+        assert_eq!(scroll.columns()[0].width(), 1.0);
+
+        scroll.adjust_width(clients[0], -5.0);
+        assert_eq!(scroll.columns()[0].width(), 0.05);
+    }
+
+    #[test]
+    fn neighbours_are_found_in_the_adjacent_columns() {
+        let clients = entities(3);
+        let mut scroll = ScrollState::default();
+        scroll.sync(&clients, &clients, None, 0.5);
+        scroll.consume(clients[1]);
+
+        assert_eq!(
+            scroll.neighbour(clients[2], Direction::Left, &clients),
+            Some(clients[0])
+        );
+        assert_eq!(
+            scroll.neighbour(clients[1], Direction::Right, &clients),
+            Some(clients[2])
+        );
+        assert_eq!(
+            scroll.neighbour(clients[0], Direction::Left, &clients),
+            None
+        );
+        assert_eq!(
+            scroll.neighbour(clients[0], Direction::Down, &clients),
+            None
+        );
+    }
+
+    #[test]
+    fn new_columns_are_inserted_right_of_the_focused_client() {
+        let clients = entities(3);
+        let mut scroll = ScrollState::default();
+        scroll.sync(&clients[..2], &clients[..2], None, 0.5);
+        scroll.sync(&clients, &clients, Some(clients[0]), 0.5);
+
+        let order = scroll
+            .columns()
+            .iter()
+            .flat_map(|column| column.clients().clone())
+            .collect::<Vec<Entity>>();
+        assert_eq!(order, vec![clients[0], clients[2], clients[1]]);
+    }
+}
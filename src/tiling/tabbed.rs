@@ -0,0 +1,88 @@
+use crate::prelude::*;
+
+/// How the tabs of a tab bar are laid out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TabStyle {
+    /// All tabs are placed next to each other in a single row.
+    Tabbed,
+
+    /// Every tab has its own row.
+    Stacked,
+}
+
+impl TabStyle {
+    /// The height of a tab bar with the given amount of [`tabs`] that are [`tab_height`] high.
+    pub fn bar_height(&self, tabs: usize, tab_height: u32) -> u32 {
+        match self {
+            TabStyle::Tabbed => tab_height,
+            TabStyle::Stacked => tab_height * tabs as u32,
+        }
+    }
+
+    /// The index of the tab at the given position in a tab bar that is [`width`] wide.
+    pub fn tab_at(
+        &self,
+        x: u32,
+        y: u32,
+        width: u32,
+        tab_height: u32,
+        tabs: usize,
+    ) -> Option<usize> {
+        let idx = match self {
+            TabStyle::Tabbed => (x as usize * tabs).checked_div(width as usize)?,
+            TabStyle::Stacked => y.checked_div(tab_height)? as usize,
+        };
+
+        (idx < tabs).then_some(idx)
+    }
+}
+
+/// The tab bar that was last drawn in the frame of a client.
+#[derive(Component, Debug, Clone, PartialEq, Getters)]
+#[constructor(named(new), fields(clients, style, width))]
+pub struct TabBar {
+    /// The clients listed in the tab bar, in order.
+    clients: Vec<Entity>,
+
+    /// How the tabs are laid out.
+    style: TabStyle,
+
+    /// The width of the tab bar.
+    width: u32,
+}
+
+/// Shows one client at a time in the tag area, with a single row of tabs above it.
+pub struct Tabbed;
+
+impl LayoutEngine for Tabbed {
+    fn arrange(
+        &self,
+        area: Geometry,
+        clients: &[LayoutClient],
+        tag: &Tag,
+    ) -> Vec<(Entity, Geometry)> {
+        Monocle.arrange(area, clients, tag)
+    }
+
+    fn tabs(&self) -> Option<TabStyle> {
+        Some(TabStyle::Tabbed)
+    }
+}
+
+/// Shows one client at a time in the tag area, with one row per client above it.
+pub struct Stacked;
+
+impl LayoutEngine for Stacked {
+    fn arrange(
+        &self,
+        area: Geometry,
+        clients: &[LayoutClient],
+        tag: &Tag,
+    ) -> Vec<(Entity, Geometry)> {
+        Monocle.arrange(area, clients, tag)
+    }
+
+    fn tabs(&self) -> Option<TabStyle> {
+        Some(TabStyle::Stacked)
+    }
+}
@@ -0,0 +1,723 @@
+use serde::Deserialize;
+
+use crate::prelude::*;
+
+/// The axis along which a container is split.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SplitAxis {
+    /// The children are placed next to each other.
+    #[serde(rename = "vertical")]
+    Vertical,
+
+    /// The children are placed above each other.
+    #[serde(rename = "horizontal")]
+    Horizontal,
+}
+
+impl SplitAxis {
+    /// The axis splitting a container so that a new child can be placed in the given [`direction`].
+    pub fn from_direction(direction: Direction) -> Self {
+        match direction {
+            Direction::Left | Direction::Right => Self::Vertical,
+            Direction::Up | Direction::Down => Self::Horizontal,
+        }
+    }
+
+    /// The other axis.
+    pub fn toggled(self) -> Self {
+        match self {
+            Self::Vertical => Self::Horizontal,
+            Self::Horizontal => Self::Vertical,
+        }
+    }
+}
+
+/// A node of a [`SplitTree`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum SplitNode {
+    /// A leaf holding a single client.
+    Client(Entity),
+
+    /// A container split into two children.
+    Container {
+        /// The axis along which this container is split.
+        axis: SplitAxis,
+
+        /// The percentage of the space that the first child is using.
+        ratio: f32,
+
+        /// The left or top child.
+        first: Box<SplitNode>,
+
+        /// The right or bottom child.
+        second: Box<SplitNode>,
+    },
+}
+
+impl SplitNode {
+    /// Whether this node is the leaf of the given [`client`].
+    fn is_client(&self, client: Entity) -> bool {
+        self == &SplitNode::Client(client)
+    }
+
+    /// Whether this node or any of its children hold the given [`client`].
+    fn contains(&self, client: Entity) -> bool {
+        match self {
+            SplitNode::Client(leaf) => *leaf == client,
+            SplitNode::Container { first, second, .. } => {
+                first.contains(client) || second.contains(client)
+            }
+        }
+    }
+
+    /// Whether any client of this node is contained in the given [`clients`].
+    fn contains_any(&self, clients: &[Entity]) -> bool {
+        clients.iter().any(|client| self.contains(*client))
+    }
+
+    /// Collects all clients of this node, from left to right.
+    fn clients(&self, clients: &mut Vec<Entity>) {
+        match self {
+            SplitNode::Client(leaf) => clients.push(*leaf),
+            SplitNode::Container { first, second, .. } => {
+                first.clients(clients);
+                second.clients(clients);
+            }
+        }
+    }
+
+    /// The depth of the leaf of the given [`client`].
+    fn depth_of(&self, client: Entity) -> Option<usize> {
+        match self {
+            SplitNode::Client(leaf) => (*leaf == client).then_some(0),
+            SplitNode::Container { first, second, .. } => first
+                .depth_of(client)
+                .or_else(|| second.depth_of(client))
+                .map(|depth| depth + 1),
+        }
+    }
+
+    /// Removes the given [`client`] from this node, collapsing containers that are left with a
+    /// single child. Returns [`None`] if nothing is left of this node.
+    fn remove(self, client: Entity) -> Option<SplitNode> {
+        match self {
+            SplitNode::Client(leaf) if leaf == client => None,
+            SplitNode::Client(leaf) => Some(SplitNode::Client(leaf)),
+            SplitNode::Container {
+                axis,
+                ratio,
+                first,
+                second,
+            } => match ((*first).remove(client), (*second).remove(client)) {
+                (Some(first), Some(second)) => Some(SplitNode::Container {
+                    axis,
+                    ratio,
+                    first: Box::new(first),
+                    second: Box::new(second),
+                }),
+                (Some(node), None) | (None, Some(node)) => Some(node),
+                (None, None) => None,
+            },
+        }
+    }
+
+    /// Splits the leaf of the given [`target`], placing the given [`client`] in the given
+    /// [`direction`] of it.
+    fn insert(self, target: Entity, client: Entity, direction: Direction) -> SplitNode {
+        match self {
+            SplitNode::Client(leaf) if leaf == target => {
+                let (first, second) = match direction {
+                    Direction::Left | Direction::Up => (client, leaf),
+                    Direction::Right | Direction::Down => (leaf, client),
+                };
+
+                SplitNode::Container {
+                    axis: SplitAxis::from_direction(direction),
+                    ratio: 0.5,
+                    first: Box::new(SplitNode::Client(first)),
+                    second: Box::new(SplitNode::Client(second)),
+                }
+            }
+            SplitNode::Client(leaf) => SplitNode::Client(leaf),
+            SplitNode::Container {
+                axis,
+                ratio,
+                first,
+                second,
+            } => SplitNode::Container {
+                axis,
+                ratio,
+                first: Box::new((*first).insert(target, client, direction)),
+                second: Box::new((*second).insert(target, client, direction)),
+            },
+        }
+    }
+
+    /// Replaces the leaf of the [`old`] client with the [`new`] client.
+    fn replace(&mut self, old: Entity, new: Entity) {
+        match self {
+            SplitNode::Client(leaf) if *leaf == old => *leaf = new,
+            SplitNode::Client(_) => {}
+            SplitNode::Container { first, second, .. } => {
+                first.replace(old, new);
+                second.replace(old, new);
+            }
+        }
+    }
+
+    /// Swaps the leaves of the given clients.
+    fn swap(&mut self, first_client: Entity, second_client: Entity) {
+        match self {
+            SplitNode::Client(leaf) if *leaf == first_client => *leaf = second_client,
+            SplitNode::Client(leaf) if *leaf == second_client => *leaf = first_client,
+            SplitNode::Client(_) => {}
+            SplitNode::Container { first, second, .. } => {
+                first.swap(first_client, second_client);
+                second.swap(first_client, second_client);
+            }
+        }
+    }
+
+    /// Finds the container that directly holds the leaf of the given [`client`].
+    fn parent_of_mut(&mut self, client: Entity) -> Option<&mut SplitNode> {
+        let is_parent = matches!(
+            self,
+            SplitNode::Container { first, second, .. }
+                if first.is_client(client) || second.is_client(client)
+        );
+
+        if is_parent {
+            return Some(self);
+        }
+
+        match self {
+            SplitNode::Client(_) => None,
+            SplitNode::Container { first, second, .. } => {
+                if first.contains(client) {
+                    first.parent_of_mut(client)
+                } else {
+                    second.parent_of_mut(client)
+                }
+            }
+        }
+    }
+
+    /// Rotates this node and all of its children by 90 degrees clockwise.
+    fn rotate(&mut self) {
+        if let SplitNode::Container {
+            axis,
+            ratio,
+            first,
+            second,
+        } = self
+        {
+            if *axis == SplitAxis::Horizontal {
+                std::mem::swap(first, second);
+                *ratio = 1.0 - *ratio;
+            }
+
+            *axis = axis.toggled();
+            first.rotate();
+            second.rotate();
+        }
+    }
+
+    /// Mirrors this node and all of its children along the given [`flip_axis`].
+    fn flip(&mut self, flip_axis: SplitAxis) {
+        if let SplitNode::Container {
+            axis,
+            ratio,
+            first,
+            second,
+        } = self
+        {
+            if *axis == flip_axis {
+                std::mem::swap(first, second);
+                *ratio = 1.0 - *ratio;
+            }
+
+            first.flip(flip_axis);
+            second.flip(flip_axis);
+        }
+    }
+
+    /// Moves the boundary at the given [`edge`] of the leaf of the given [`client`] to the given
+    /// position, when this node is arranged in the given [`area`]. Returns whether a boundary was
+    /// moved.
+    fn resize(
+        &mut self,
+        area: Geometry,
+        gaps: Gaps,
+        visible: &[Entity],
+        client: Entity,
+        edge: Direction,
+        position: (i32, i32),
+    ) -> bool {
+        let SplitNode::Container {
+            axis,
+            ratio,
+            first,
+            second,
+        } = self
+        else {
+            return false;
+        };
+
+        match (first.contains_any(visible), second.contains_any(visible)) {
+            (true, true) => {}
+            (true, false) => return first.resize(area, gaps, visible, client, edge, position),
+            (false, true) => return second.resize(area, gaps, visible, client, edge, position),
+            (false, false) => return false,
+        }
+
+        let (vertical, gap) = match axis {
+            SplitAxis::Vertical => (true, gaps.inner_horizontal()),
+            SplitAxis::Horizontal => (false, gaps.inner_vertical()),
+        };
+
+        let (first_area, second_area) = area.split(vertical, *ratio, gap);
+        let (in_first, handled) = if first.contains(client) {
+            (
+                true,
+                first.resize(first_area, gaps, visible, client, edge, position),
+            )
+        } else if second.contains(client) {
+            (
+                false,
+                second.resize(second_area, gaps, visible, client, edge, position),
+            )
+        } else {
+            return false;
+        };
+
+        if handled {
+            return true;
+        }
+
+        // the boundary of this container is right of or below the first child
+        let towards_boundary = match (axis, in_first) {
+            (SplitAxis::Vertical, true) => Direction::Right,
+            (SplitAxis::Vertical, false) => Direction::Left,
+            (SplitAxis::Horizontal, true) => Direction::Down,
+            (SplitAxis::Horizontal, false) => Direction::Up,
+        };
+
+        if edge != towards_boundary {
+            return false;
+        }
+
+        let (offset, size) = match axis {
+            SplitAxis::Vertical => (position.0 - area.x(), area.width()),
+            SplitAxis::Horizontal => (position.1 - area.y(), area.height()),
+        };
+
+        if size > 0 {
+            *ratio = (offset as f32 / size as f32).clamp(0.05, 0.95);
+        }
+
+        true
+    }
+
+    /// Arranges all leaves of this node whose client is contained in [`visible`] in the given
+    /// [`area`]. Containers with only one visible child give the whole area to that child.
+    fn arrange(
+        &self,
+        area: Geometry,
+        gaps: Gaps,
+        visible: &[Entity],
+        geometries: &mut Vec<(Entity, Geometry)>,
+    ) {
+        match self {
+            SplitNode::Client(leaf) => {
+                if visible.contains(leaf) {
+                    geometries.push((*leaf, area));
+                }
+            }
+            SplitNode::Container {
+                axis,
+                ratio,
+                first,
+                second,
+            } => match (first.contains_any(visible), second.contains_any(visible)) {
+                (true, true) => {
+                    let (vertical, gap) = match axis {
+                        SplitAxis::Vertical => (true, gaps.inner_horizontal()),
+                        SplitAxis::Horizontal => (false, gaps.inner_vertical()),
+                    };
+
+                    let (first_area, second_area) = area.split(vertical, *ratio, gap);
+                    first.arrange(first_area, gaps, visible, geometries);
+                    second.arrange(second_area, gaps, visible, geometries);
+                }
+                (true, false) => first.arrange(area, gaps, visible, geometries),
+                (false, true) => second.arrange(area, gaps, visible, geometries),
+                (false, false) => {}
+            },
+        }
+    }
+}
+
+/// A binary space partitioning tree of containers that holds the clients of a tag.
+#[derive(Debug, Clone, Default, PartialEq, Getters)]
+pub struct SplitTree {
+    /// The root node, if the tree holds any client.
+    root: Option<SplitNode>,
+
+    /// The direction in which the next client is placed next to the selected client.
+    preselection: Option<Direction>,
+}
+
+impl SplitTree {
+    /// Whether this tree holds the given [`client`].
+    pub fn contains(&self, client: Entity) -> bool {
+        self.root.as_ref().is_some_and(|root| root.contains(client))
+    }
+
+    /// All clients of this tree, from left to right.
+    pub fn clients(&self) -> Vec<Entity> {
+        let mut clients = vec![];
+        if let Some(root) = &self.root {
+            root.clients(&mut clients);
+        }
+
+        clients
+    }
+
+    /// Removes all clients that are not part of the given tag [`clients`] and inserts all [`tiled`]
+    /// clients that are missing next to the [`focused`] client.
+    pub fn sync(&mut self, clients: &[Entity], tiled: &[Entity], focused: Option<Entity>) {
+        for client in self.clients() {
+            if !clients.contains(&client) {
+                self.remove(client);
+            }
+        }
+
+        for client in tiled {
+            if !self.contains(*client) {
+                self.insert(*client, focused.filter(|focused| self.contains(*focused)));
+            }
+        }
+    }
+
+    /// Inserts the given [`client`] next to the given [`target`] or the last client, consuming the
+    /// preselected direction. Without a preselection, clients are placed to the right of the target
+    /// if it is at an even depth and below it otherwise.
+    pub fn insert(&mut self, client: Entity, target: Option<Entity>) {
+        let Some(root) = self.root.take() else {
+            self.root = Some(SplitNode::Client(client));
+            return;
+        };
+
+        let target = target.or_else(|| {
+            let mut clients = vec![];
+            root.clients(&mut clients);
+            clients.last().copied()
+        });
+
+        let Some(target) = target else {
+            self.root = Some(root);
+            return;
+        };
+
+        let direction = self.preselection.take().unwrap_or_else(|| {
+            if root.depth_of(target).unwrap_or_default() % 2 == 0 {
+                Direction::Right
+            } else {
+                Direction::Down
+            }
+        });
+
+        self.root = Some(root.insert(target, client, direction));
+    }
+
+    /// Removes the given [`client`] from this tree.
+    pub fn remove(&mut self, client: Entity) {
+        self.root = self.root.take().and_then(|root| root.remove(client));
+    }
+
+    /// Moves the given [`client`] next to the given [`target`] in the given [`direction`].
+    pub fn move_to(&mut self, client: Entity, target: Entity, direction: Direction) {
+        if client == target || !self.contains(client) || !self.contains(target) {
+            return;
+        }
+
+        self.remove(client);
+        self.root = self
+            .root
+            .take()
+            .map(|root| root.insert(target, client, direction));
+    }
+
+    /// Replaces the [`old`] client with the [`new`] client, keeping its position.
+    pub fn replace(&mut self, old: Entity, new: Entity) {
+        if let Some(root) = &mut self.root {
+            root.replace(old, new);
+        }
+    }
+
+    /// Swaps the positions of the given clients.
+    pub fn swap(&mut self, first: Entity, second: Entity) {
+        if let Some(root) = &mut self.root {
+            root.swap(first, second);
+        }
+    }
+
+    /// Preselects the direction in which the next client is placed. Preselecting the same direction
+    /// again cancels the preselection.
+    pub fn preselect(&mut self, direction: Direction) {
+        if self.preselection == Some(direction) {
+            self.preselection = None;
+        } else {
+            self.preselection = Some(direction);
+        }
+    }
+
+    /// Grows the share of the given [`client`] in its container by [`delta`].
+    pub fn adjust_ratio(&mut self, client: Entity, delta: f32) {
+        let Some(SplitNode::Container { ratio, first, .. }) = self
+            .root
+            .as_mut()
+            .and_then(|root| root.parent_of_mut(client))
+        else {
+            return;
+        };
+
+        let delta = if first.is_client(client) {
+            delta
+        } else {
+            -delta
+        };
+
+        *ratio = (*ratio + delta).clamp(0.05, 0.95);
+    }
+
+    /// Rotates the container of the given [`client`] by 90 degrees clockwise.
+    pub fn rotate(&mut self, client: Entity) {
+        if let Some(container) = self
+            .root
+            .as_mut()
+            .and_then(|root| root.parent_of_mut(client))
+        {
+            container.rotate();
+        }
+    }
+
+    /// Mirrors the container of the given [`client`] along the given [`axis`].
+    pub fn flip(&mut self, client: Entity, axis: SplitAxis) {
+        if let Some(container) = self
+            .root
+            .as_mut()
+            .and_then(|root| root.parent_of_mut(client))
+        {
+            container.flip(axis);
+        }
+    }
+
+    /// Moves the boundary at the given [`edge`] of the given [`client`] to the given position, when
+    /// the [`visible`] clients of this tree are arranged in the given [`area`].
+    pub fn resize(
+        &mut self,
+        area: Geometry,
+        gaps: Gaps,
+        visible: &[Entity],
+        client: Entity,
+        edge: Direction,
+        x: i32,
+        y: i32,
+    ) {
+        if let Some(root) = &mut self.root {
+            root.resize(gaps.apply_outer(area), gaps, visible, client, edge, (x, y));
+        }
+    }
+
+    /// Arranges the [`visible`] clients of this tree in the given [`area`].
+    pub fn arrange(
+        &self,
+        area: Geometry,
+        gaps: Gaps,
+        visible: &[Entity],
+    ) -> Vec<(Entity, Geometry)> {
+        let mut geometries = vec![];
+        if let Some(root) = &self.root {
+            root.arrange(gaps.apply_outer(area), gaps, visible, &mut geometries);
+        }
+
+        geometries
+    }
+}
+
+/// A manual tiling layout where the clients are arranged by the [`SplitTree`] of their tag.
+pub struct Tree;
+
+impl LayoutEngine for Tree {
+    fn prepare(&self, _area: Geometry, clients: &[LayoutClient], tag: &mut Tag) {
+        let (tiled, kept, focused) = layout_state_clients(clients, tag);
+        tag.tree_mut().sync(&kept, &tiled, focused);
+    }
+
+    fn arrange(
+        &self,
+        area: Geometry,
+        clients: &[LayoutClient],
+        tag: &Tag,
+    ) -> Vec<(Entity, Geometry)> {
+        let visible = clients
+            .iter()
+            .map(LayoutClient::entity)
+            .collect::<Vec<Entity>>();
+
+        tag.tree().arrange(area, tag.gaps(), &visible)
+    }
+
+    fn resize(
+        &self,
+        area: Geometry,
+        tiled: &[Entity],
+        tag: &mut Tag,
+        client: Entity,
+        edge: Direction,
+        x: i32,
+        y: i32,
+    ) {
+        let gaps = tag.gaps();
+        tag.tree_mut().resize(area, gaps, tiled, client, edge, x, y);
+    }
+}
+
+pub fn handle_tree_actions(
+    mut events: EventReader<KeybindTriggered>,
+    mut monitors: Query<&mut Tags, With<Monitor>>,
+    clients: Query<&Geometry, (With<Client>, Without<Monitor>)>,
+) {
+    for event in events.read() {
+        if !matches!(
+            event.action(),
+            KeybindAction::Preselect(_)
+                | KeybindAction::AdjustSplitRatio(_)
+                | KeybindAction::RotateSplit
+                | KeybindAction::FlipSplit(_)
+                | KeybindAction::MoveToContainer(_)
+        ) {
+            continue;
+        }
+
+        let Some(client) = event.client() else {
+            continue;
+        };
+
+        for mut tags in &mut monitors {
+            if !tags.contains_client(client) {
+                continue;
+            }
+
+            let Some(tag) = tags.current_mut() else {
+                continue;
+            };
+
+            let neighbours = tag
+                .tree()
+                .clients()
+                .into_iter()
+                .filter(|neighbour| *neighbour != client)
+                .filter_map(|neighbour| Some((neighbour, *clients.get(neighbour).ok()?)))
+                .collect::<Vec<(Entity, Geometry)>>();
+
+            let tree = tag.tree_mut();
+            match event.action() {
+                KeybindAction::Preselect(direction) => tree.preselect(*direction),
+                KeybindAction::AdjustSplitRatio(percent) => {
+                    tree.adjust_ratio(client, *percent as f32 / 100.0)
+                }
+                KeybindAction::RotateSplit => tree.rotate(client),
+                KeybindAction::FlipSplit(axis) => tree.flip(client, *axis),
+                KeybindAction::MoveToContainer(direction) => {
+                    let Ok(geometry) = clients.get(client) else {
+                        continue;
+                    };
+
+                    if let Some(target) = geometry.neighbour(&neighbours, *direction) {
+                        tree.move_to(client, target, *direction);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::testing::entities;
+
+    #[test]
+    fn inserting_alternates_between_splitting_right_and_below() {
+        let clients = entities(3);
+        let mut tree = SplitTree::default();
+        for client in &clients {
+            tree.insert(*client, None);
+        }
+
+        assert_eq!(tree.clients(), clients);
+        assert_eq!(
+            tree.root(),
+            &Some(SplitNode::Container {
+                axis: SplitAxis::Vertical,
+                ratio: 0.5,
+                first: Box::new(SplitNode::Client(clients[0])),
+                second: Box::new(SplitNode::Container {
+                    axis: SplitAxis::Horizontal,
+                    ratio: 0.5,
+                    first: Box::new(SplitNode::Client(clients[1])),
+                    second: Box::new(SplitNode::Client(clients[2])),
+                }),
+            })
+        );
+    }
+
+    #[test]
+    fn inserting_consumes_the_preselection() {
+        let clients = entities(2);
+        let mut tree = SplitTree::default();
+        tree.insert(clients[0], None);
+        tree.preselect(Direction::Left);
+        tree.insert(clients[1], Some(clients[0]));
+
+        assert_eq!(tree.clients(), vec![clients[1], clients[0]]);
+        assert_eq!(tree.preselection(), &None);
+    }
+
+    #[test]
+    fn removing_collapses_containers() {
+        let clients = entities(3);
+        let mut tree = SplitTree::default();
+        for client in &clients {
+            tree.insert(*client, None);
+        }
+
+        tree.remove(clients[1]);
+        assert_eq!(
+            tree.root(),
+            &Some(SplitNode::Container {
+                axis: SplitAxis::Vertical,
+                ratio: 0.5,
+                first: Box::new(SplitNode::Client(clients[0])),
+                second: Box::new(SplitNode::Client(clients[2])),
+            })
+        );
+
+        tree.remove(clients[0]);
+        tree.remove(clients[2]);
+        assert_eq!(tree.root(), &None);
+    }
+
+    #[test]
+    fn syncing_removes_clients_that_left_the_tag() {
+        let clients = entities(3);
+        let mut tree = SplitTree::default();
+        tree.sync(&clients, &clients, None);
+        tree.sync(&clients[1..], &clients[1..], Some(clients[1]));
+
+        assert_eq!(tree.clients(), clients[1..].to_vec());
+    }
+}
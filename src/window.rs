@@ -2,5 +2,3 @@ use crate::prelude::*;
 
 /// A window identified by its ID.
 pub type Window = u32;
-
-wrapper!(SelectedWindow(Window));
@@ -0,0 +1,104 @@
+use crate::prelude::*;
+
+pub fn handle_workspace_actions(
+    mut events: EventReader<KeybindTriggered>,
+    mut monitors: Query<
+        (Entity, &mut Tags, &mut SelectedTagset, &mut PreviousTagset),
+        With<Monitor>,
+    >,
+    config: Res<MainConfig>,
+    layouts: Res<Layouts>,
+) {
+    for event in events.read() {
+        let (KeybindAction::ViewWorkspace(label) | KeybindAction::RenameWorkspace(label)) =
+            event.action()
+        else {
+            continue;
+        };
+
+        if label.is_empty() {
+            warn!("ignoring workspace without a label");
+            continue;
+        }
+
+        for (monitor, mut tags, mut selected_tagset, mut previous_tagset) in &mut monitors {
+            if event.monitor() != Some(monitor) {
+                continue;
+            }
+
+            if let KeybindAction::RenameWorkspace(_) = event.action() {
+                if tags.with_label(label).is_some() {
+                    warn!("cannot rename workspace, {label} already exists");
+                    continue;
+                }
+
+                if let Some(tag) = tags.current_mut() {
+                    tag.change_label(label.clone());
+                }
+                continue;
+            }
+
+            let idx = match tags.with_label(label) {
+                Some(tag) => tag.idx(),
+                None => {
+                    let Some(idx) = tags.free_idx() else {
+                        warn!("cannot create workspace {label}, all {MAX_TAGS} tags are in use");
+                        continue;
+                    };
+
+                    let mut tag = Tag::configured(idx, label.clone(), &config, &layouts);
+                    tag.change_dynamic(true);
+                    tags.push(tag);
+                    idx
+                }
+            };
+
+            view_tagset(
+                Tagset::single(idx),
+                &mut tags,
+                &mut selected_tagset,
+                &mut previous_tagset,
+            );
+        }
+    }
+}
+
+/// Removes dynamic workspaces once they are empty and no longer viewed.
+pub fn remove_abandoned_workspaces(
+    mut monitors: Query<(&mut Tags, &mut PreviousTagset), (With<Monitor>, Changed<Tags>)>,
+) {
+    for (mut tags, mut previous_tagset) in &mut monitors {
+        // most changes do not abandon any workspace, so only actual removals are reported
+        let abandoned = tags.bypass_change_detection().remove_abandoned();
+        if abandoned.is_empty() {
+            continue;
+        }
+
+        tags.set_changed();
+        **previous_tagset = previous_tagset.difference(abandoned);
+    }
+}
+
+/// Publishes the tags of the first monitor as desktops for pagers whenever they change.
+pub fn announce_workspaces(
+    monitors: Query<(&Tags, &MonitorId), (With<Monitor>, Changed<Tags>)>,
+    conn: Res<PlatformConnection>,
+    root_window: Res<MainRootWindow>,
+) {
+    for (tags, id) in monitors {
+        if **id != 0 {
+            continue;
+        }
+
+        let names = tags
+            .iter()
+            .map(|tag| tag.label().clone())
+            .collect::<Vec<String>>();
+        let current = tags
+            .iter()
+            .position(|tag| tag.state().selected())
+            .unwrap_or(0);
+
+        RWMP::set_desktops(&names, current, **root_window, &conn);
+    }
+}
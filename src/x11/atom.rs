@@ -1,4 +1,62 @@
+use std::sync::OnceLock;
+
 use crate::prelude::*;
 
 wrapper!(NetWMProtocols(u32));
 wrapper!(NetWMDeleteWindow(u32));
+wrapper!(NetWMState(u32));
+wrapper!(NetWMStateSticky(u32));
+wrapper!(RwmViewWorkspace(u32));
+wrapper!(RwmRenameWorkspace(u32));
+
+static ATOMS: OnceLock<Atoms> = OnceLock::new();
+
+/// The atoms of the properties rwm publishes, which are interned once at startup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Getters)]
+pub struct Atoms {
+    /// `_NET_WM_STATE`
+    wm_state: u32,
+
+    /// `_NET_WM_STATE_STICKY`
+    wm_state_sticky: u32,
+
+    /// `_NET_NUMBER_OF_DESKTOPS`
+    number_of_desktops: u32,
+
+    /// `_NET_CURRENT_DESKTOP`
+    current_desktop: u32,
+
+    /// `_NET_DESKTOP_NAMES`
+    desktop_names: u32,
+
+    /// `_RWM_LAYOUT_SYMBOL`
+    layout_symbol: u32,
+
+    /// `UTF8_STRING`
+    utf8_string: u32,
+}
+
+/// Interns the atoms of the properties rwm publishes, so they are not interned again whenever a
+/// property is published.
+pub fn intern_atoms(conn: &X11Connection) -> Atoms {
+    let atoms = Atoms {
+        wm_state: intern_atom(conn, b"_NET_WM_STATE"),
+        wm_state_sticky: intern_atom(conn, b"_NET_WM_STATE_STICKY"),
+        number_of_desktops: intern_atom(conn, b"_NET_NUMBER_OF_DESKTOPS"),
+        current_desktop: intern_atom(conn, b"_NET_CURRENT_DESKTOP"),
+        desktop_names: intern_atom(conn, b"_NET_DESKTOP_NAMES"),
+        layout_symbol: intern_atom(conn, b"_RWM_LAYOUT_SYMBOL"),
+        utf8_string: intern_atom(conn, b"UTF8_STRING"),
+    };
+
+    let _ = ATOMS.set(atoms);
+
+    atoms
+}
+
+/// Gets the interned atoms, or panic if they were not interned yet.
+pub fn atoms() -> &'static Atoms {
+    ATOMS
+        .get()
+        .unwrap_or_else(|| die!("atoms not interned yet"))
+}
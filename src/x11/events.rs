@@ -2,7 +2,8 @@ use std::cmp::Reverse;
 
 use x11rb::connection::Connection;
 use x11rb::protocol::xproto::{
-    ConnectionExt as _, KeyPressEvent, MapNotifyEvent, MapRequestEvent, QueryPointerReply,
+    AtomEnum, ClientMessageEvent, ConnectionExt as _, ExposeEvent, KeyPressEvent, KeyReleaseEvent,
+    MapNotifyEvent, MapRequestEvent, Property, PropertyNotifyEvent, QueryPointerReply,
 };
 use x11rb::wrapper::ConnectionExt as _;
 use x11rb::x11_utils::X11Error;
@@ -11,14 +12,14 @@ use x11rb::{
     protocol::{
         Event,
         xproto::{
-            ButtonPressEvent, ButtonReleaseEvent, ConfigureWindowAux, EnterNotifyEvent, InputFocus,
-            MotionNotifyEvent, NotifyMode, StackMode, UnmapNotifyEvent,
+            Allow, ButtonPressEvent, ButtonReleaseEvent, ConfigureWindowAux, EnterNotifyEvent,
+            InputFocus, MotionNotifyEvent, NotifyMode, UnmapNotifyEvent,
         },
     },
 };
 
 use crate::prelude::*;
-use crate::x11::IGNORED_SEQUENCES;
+use crate::x11::{IGNORED_SEQUENCES, LAYOUT_SEQUENCE};
 
 #[derive(Event, Debug, Clone)]
 pub enum X11Event {
@@ -29,13 +30,18 @@ pub enum X11Event {
     ButtonPress(ButtonPressEvent),
     ButtonRelease(ButtonReleaseEvent),
     KeyPress(KeyPressEvent),
+    KeyRelease(KeyReleaseEvent),
     MapNotify(MapNotifyEvent),
+    PropertyNotify(PropertyNotifyEvent),
+    Expose(ExposeEvent),
+    ClientMessage(ClientMessageEvent),
     Error(X11Error),
 }
 
 pub fn poll_events(mut events: EventWriter<X11Event>, conn: ResMut<X11Connection>) {
     conn.flush().unwrap();
     let mut ignored_sequences = IGNORED_SEQUENCES.lock().unwrap();
+    let mut layout_sequence = LAYOUT_SEQUENCE.lock().unwrap();
     
     loop {
         match conn.poll_for_event() {
@@ -55,6 +61,15 @@ pub fn poll_events(mut events: EventWriter<X11Event>, conn: ResMut<X11Connection
                         }
                         ignored_sequences.pop();
                     }
+
+                    // same trick as above, this checks for "seqno < layout_seqno"
+                    if let Some(layout_seqno) = *layout_sequence {
+                        if layout_seqno.wrapping_sub(seqno).wrapping_sub(1) < u16::MAX / 2 {
+                            should_ignore |= matches!(event, Event::EnterNotify(_));
+                        } else {
+                            *layout_sequence = None;
+                        }
+                    }
                 }
 
                 if should_ignore {
@@ -69,7 +84,11 @@ pub fn poll_events(mut events: EventWriter<X11Event>, conn: ResMut<X11Connection
                     Event::ButtonPress(ev) => Some(X11Event::ButtonPress(ev)),
                     Event::ButtonRelease(ev) => Some(X11Event::ButtonRelease(ev)),
                     Event::KeyPress(ev) => Some(X11Event::KeyPress(ev)),
+                    Event::KeyRelease(ev) => Some(X11Event::KeyRelease(ev)),
                     Event::MapNotify(ev) => Some(X11Event::MapNotify(ev)),
+                    Event::PropertyNotify(ev) => Some(X11Event::PropertyNotify(ev)),
+                    Event::Expose(ev) => Some(X11Event::Expose(ev)),
+                    Event::ClientMessage(ev) => Some(X11Event::ClientMessage(ev)),
                     Event::Error(err) => Some(X11Event::Error(err)),
                     _ => {
                         // info!("ignored event: {event:#?}");
@@ -87,13 +106,40 @@ pub fn poll_events(mut events: EventWriter<X11Event>, conn: ResMut<X11Connection
     }
 }
 
+/// Remembers the sequence number of the next request whenever the layout of a monitor was
+/// arranged, so that the pointer crossings caused by moving clients are ignored.
+pub fn mark_layout_crossings(
+    monitors: Query<(), (With<Monitor>, Or<(Changed<Tags>, Changed<SelectedClient>)>)>,
+    conn: Res<X11Connection>,
+) {
+    if monitors.is_empty() {
+        return;
+    }
+
+    match conn.no_operation() {
+        Ok(cookie) => {
+            *LAYOUT_SEQUENCE.lock().unwrap() = Some(cookie.sequence_number() as u16);
+        }
+        Err(e) => error!("failed marking layout crossings: {e}"),
+    }
+}
+
 pub fn handle_map_request(
     mut events: EventReader<X11Event>,
-    mut monitors: Query<&mut Tags, With<Monitor>>,
+    mut monitors: Query<(Entity, &mut Tags, &Geometry), With<Monitor>>,
+    scratchpads: Query<&Scratchpad, With<Client>>,
+    mut pending: ResMut<PendingScratchpads>,
     mut commands: Commands,
     conn: ResMut<X11Connection>,
     main_root: Res<MainRootWindow>,
+    config: Res<MainConfig>,
 ) {
+    // scratchpads are only spawned after the end of this system
+    let mut claimed = scratchpads
+        .iter()
+        .map(|scratchpad| scratchpad.0.clone())
+        .collect::<Vec<String>>();
+
     for event in events.read() {
         if let X11Event::MapRequest(event) = event {
             let geom = &conn
@@ -101,27 +147,86 @@ pub fn handle_map_request(
                 .unwrap()
                 .reply()
                 .unwrap();
+            let geometry = Geometry::new(
+                geom.x as i32,
+                geom.y as i32,
+                geom.width as u32,
+                geom.height as u32,
+            );
+
+            let monitor_geoms = monitors
+                .iter()
+                .map(|(_, _, geometry)| *geometry)
+                .collect::<Vec<Geometry>>();
+            let monitor = find_monitor(geometry, monitor_geoms) as usize;
 
-            for mut tags in &mut monitors {
-                let tag = tags.get_mut(0).unwrap(); // TODO: tagging
-                if let Err(e) = manage(
+            let Some((monitor, mut tags, monitor_geometry)) = monitors.iter_mut().nth(monitor)
+            else {
+                continue;
+            };
+
+            let classes = window_class(&conn, event.window).unwrap_or_default();
+            let title = window_name(&conn, event.window).unwrap_or_default();
+            if let Some(scratchpad) = config.scratchpads().iter().find(|scratchpad| {
+                !claimed.contains(scratchpad.name()) && scratchpad.matches(&classes, &title)
+            }) {
+                let geometry = scratchpad.geometry(*monitor_geometry);
+
+                match manage(
                     &conn,
                     event.window,
-                    Geometry::new(
-                        geom.x as i32,
-                        geom.y as i32,
-                        geom.width as u32,
-                        geom.height as u32,
-                    ),
+                    geometry,
                     **main_root,
-                    tag,
+                    None,
                     &mut commands,
                 ) {
-                    error!(
-                        "failed managing window {}: {e} - this window will not be managed by rwm and may cause undefined behaviour",
-                        event.window
-                    );
+                    Ok((client, frame)) => {
+                        commands.entity(client).insert((
+                            Scratchpad(scratchpad.name().clone()),
+                            *ClientState::default().change_floating(true),
+                        ));
+                        show_scratchpad(&config, geometry, event.window, *frame, &conn);
+                        claimed.push(scratchpad.name().clone());
+                        pending.claimed(scratchpad.name());
+                    }
+                    Err(e) => error!(
+                        "failed managing scratchpad {}: {e} - this window will not be managed by rwm and may cause undefined behaviour",
+                        scratchpad.name()
+                    ),
+                }
+                continue;
+            }
+
+            let Some(tag) = tags.current_mut() else {
+                continue;
+            };
+
+            match manage(
+                &conn,
+                event.window,
+                geometry,
+                **main_root,
+                Some(tag),
+                &mut commands,
+            ) {
+                Ok((client, _)) => {
+                    // new windows take focus unless a rule keeps them from doing so
+                    let focus = config
+                        .rules()
+                        .iter()
+                        .filter(|rule| rule.matches(&classes, &title))
+                        .filter_map(|rule| *rule.focus())
+                        .next_back()
+                        .unwrap_or(true);
+
+                    if focus {
+                        select_client(monitor, client, event.window, &mut commands, &conn);
+                    }
                 }
+                Err(e) => error!(
+                    "failed managing window {}: {e} - this window will not be managed by rwm and may cause undefined behaviour",
+                    event.window
+                ),
             }
         }
     }
@@ -168,9 +273,13 @@ pub fn handle_unmap_notify(
 
 pub fn handle_enter_notify(
     mut events: EventReader<X11Event>,
-    query: Query<(&ClientWindow, &ClientFrame), With<Client>>,
+    mut commands: Commands,
+    query: Query<(Entity, &ClientWindow, &ClientFrame, Has<Scratchpad>), With<Client>>,
+    monitors: Query<(Entity, &Tags), With<Monitor>>,
+    mut focused: ResMut<FocusedClient>,
     conn: Res<X11Connection>,
     main_root: Res<MainRootWindow>,
+    config: Res<MainConfig>,
 ) {
     for event in events.read() {
         if let X11Event::EnterNotify(event) = event {
@@ -178,20 +287,34 @@ pub fn handle_enter_notify(
                 continue;
             }
 
-            for (window, frame) in query {
-                if event.event != **window {
+            let policy = config.focus().policy();
+            if policy == FocusPolicy::Click {
+                continue;
+            }
+
+            if event.event == **main_root {
+                if policy == FocusPolicy::FollowMouse {
+                    RWMP::focus(**main_root, &conn);
+                    *focused = FocusedClient(None);
+                }
+                continue;
+            }
+
+            for (client, window, frame, scratchpad) in query {
+                if event.event != **window && event.event != **frame {
                     continue;
                 }
 
-                conn
-                    .set_input_focus(InputFocus::PARENT, **window, CURRENT_TIME)
-                    .unwrap();
-                conn
-                    .configure_window(
-                        **frame,
-                        &ConfigureWindowAux::new().stack_mode(StackMode::ABOVE),
-                    )
-                    .unwrap();
+                focus_with_pointer(
+                    client,
+                    **window,
+                    **frame,
+                    scratchpad,
+                    &monitors,
+                    &mut commands,
+                    &conn,
+                    &config,
+                );
 
                 info!("entered window {}", event.event);
             }
@@ -199,10 +322,95 @@ pub fn handle_enter_notify(
     }
 }
 
+/// Focuses the given [`client`] after the pointer entered or clicked it, raising it only if that
+/// is configured.
+fn focus_with_pointer(
+    client: Entity,
+    window: Window,
+    frame: Window,
+    scratchpad: bool,
+    monitors: &Query<(Entity, &Tags), With<Monitor>>,
+    commands: &mut Commands,
+    conn: &X11Connection,
+    config: &MainConfig,
+) {
+    // scratchpads are focused without becoming the selected client of a monitor
+    if scratchpad {
+        RWMP::focus(window, conn);
+    } else if let Some((monitor, _)) = monitors
+        .iter()
+        .find(|(_, tags)| tags.contains_client(client))
+    {
+        select_client(monitor, client, window, commands, conn);
+    } else {
+        return;
+    }
+
+    if config.focus().raise() {
+        RWMP::raise(frame, conn);
+    }
+}
+
+/// Focuses clients that are clicked if clicking focuses clients. The click is replayed to the
+/// client afterwards.
+pub fn handle_focus_click(
+    mut events: EventReader<X11Event>,
+    mut commands: Commands,
+    query: Query<(Entity, &ClientWindow, &ClientFrame, Has<Scratchpad>), With<Client>>,
+    monitors: Query<(Entity, &Tags), With<Monitor>>,
+    conn: Res<X11Connection>,
+    config: Res<MainConfig>,
+) {
+    for event in events.read() {
+        let X11Event::ButtonPress(event) = event else {
+            continue;
+        };
+
+        if config.focus().policy() != FocusPolicy::Click {
+            continue;
+        }
+
+        for (client, window, frame, scratchpad) in query {
+            if event.event != **window && event.event != **frame {
+                continue;
+            }
+
+            focus_with_pointer(
+                client,
+                **window,
+                **frame,
+                scratchpad,
+                &monitors,
+                &mut commands,
+                &conn,
+                &config,
+            );
+        }
+
+        if let Err(e) = conn.allow_events(Allow::REPLAY_POINTER, event.time) {
+            error!("failed replaying click: {e}");
+        }
+    }
+}
+
 pub fn handle_button_press(
     mut events: EventReader<X11Event>,
     mut dragging: ResMut<Dragging>,
-    query: Query<(&ClientWindow, &ClientFrame, Has<Dragging>), With<Client>>,
+    mut resizing: ResMut<Resizing>,
+    query: Query<
+        (
+            Entity,
+            &ClientWindow,
+            &ClientFrame,
+            &Geometry,
+            &ClientState,
+            Has<Fullscreened>,
+            Has<Dragging>,
+        ),
+        With<Client>,
+    >,
+    conn: Res<X11Connection>,
+    config: Res<MainConfig>,
 ) {
     for event in events.read() {
         if let X11Event::ButtonPress(event) = event {
@@ -211,7 +419,7 @@ pub fn handle_button_press(
                 continue;
             }
 
-            for (window, frame, is_dragging) in query {
+            for (client, window, frame, geometry, state, fullscreened, is_dragging) in query {
                 if event.child != **window
                     && event.child != **frame
                     && event.event != **window
@@ -220,6 +428,18 @@ pub fn handle_button_press(
                     continue;
                 }
 
+                let margin = config.border().width() as u32 + RESIZE_MARGIN;
+                if !state.floating()
+                    && !fullscreened
+                    && let Some(edge) =
+                        geometry.nearest_edge(event.root_x as i32, event.root_y as i32, margin)
+                {
+                    debug!("started resizing");
+                    *resizing = Resizing(Some((client, edge)));
+                    RWMP::set_grab_cursor(Cursor::Resize, &conn);
+                    continue;
+                }
+
                 if is_dragging {
                     trace!("cannot drag fullscreened window");
                     continue;
@@ -233,10 +453,74 @@ pub fn handle_button_press(
     }
 }
 
+pub fn handle_tab_click(
+    mut events: EventReader<X11Event>,
+    mut commands: Commands,
+    query: Query<(&ClientFrame, &TabBar), With<Client>>,
+    clients: Query<(&ClientWindow, &ClientFrame), With<Client>>,
+    monitors: Query<(Entity, &Tags), With<Monitor>>,
+    conn: Res<X11Connection>,
+    config: Res<MainConfig>,
+) {
+    for event in events.read() {
+        if let X11Event::ButtonPress(event) = event {
+            if event.detail != 1 || u16::from(event.state) == u16::from(mod_mask()) {
+                continue;
+            }
+
+            for (frame, tab_bar) in query {
+                if event.event != **frame || event.event_x < 0 || event.event_y < 0 {
+                    continue;
+                }
+
+                let tab_height = config.tabs().height() as u32;
+                let Some(idx) = tab_bar.style().tab_at(
+                    event.event_x as u32,
+                    event.event_y as u32,
+                    tab_bar.width(),
+                    tab_height,
+                    tab_bar.clients().len(),
+                ) else {
+                    continue;
+                };
+
+                let client = tab_bar.clients()[idx];
+                let Ok((window, frame)) = clients.get(client) else {
+                    continue;
+                };
+
+                let Some((monitor, _)) = monitors
+                    .iter()
+                    .find(|(_, tags)| tags.contains_client(client))
+                else {
+                    continue;
+                };
+
+                focus_client(monitor, client, **window, **frame, &mut commands, &conn);
+            }
+        }
+    }
+}
+
 pub fn handle_button_release(
     mut events: EventReader<X11Event>,
     mut dragging: ResMut<Dragging>,
-    query: Query<(&ClientWindow, &ClientFrame, &Geometry), With<Client>>,
+    mut commands: Commands,
+    query: Query<
+        (
+            Entity,
+            &ClientWindow,
+            &ClientFrame,
+            &Geometry,
+            &ClientState,
+            Has<Fullscreened>,
+        ),
+        With<Client>,
+    >,
+    mut monitors: Query<
+        (Entity, &mut Tags, &Geometry, Option<&SelectedClient>),
+        (With<Monitor>, Without<Client>),
+    >,
     conn: Res<X11Connection>,
     root_window: Res<MainRootWindow>,
 ) {
@@ -247,9 +531,9 @@ pub fn handle_button_release(
                 continue;
             }
 
-            if dragging.is_none() {
+            let Some((dragged_frame, ..)) = **dragging else {
                 continue;
-            }
+            };
 
             *dragging = Dragging(None);
             debug!("stopped dragging");
@@ -260,7 +544,7 @@ pub fn handle_button_release(
                 ..
             } = conn.query_pointer(**root_window).unwrap().reply().unwrap();
 
-            for (window, frame, geometry) in query {
+            for (_, window, frame, geometry, ..) in &query {
                 if event.child != **window
                     && event.child != **frame
                     && event.event != **window
@@ -282,8 +566,117 @@ pub fn handle_button_release(
                     );
                 }
             }
+
+            let Some((client, ..)) = query.iter().find(|(_, _, frame, _, state, fullscreened)| {
+                ***frame == dragged_frame && !state.floating() && !fullscreened
+            }) else {
+                continue;
+            };
+
+            drop_tiled_client(
+                client,
+                ptr_x as i32,
+                ptr_y as i32,
+                &query,
+                &mut monitors,
+                &mut commands,
+            );
+        }
+    }
+}
+
+/// Drops the dragged tiled [`client`] at the given position. Dropping it onto another tiled client
+/// swaps their positions, dropping it onto another monitor moves it to the current tag of that
+/// monitor. The layout is arranged again in any case, so the client never stays at the drop
+/// position.
+fn drop_tiled_client(
+    client: Entity,
+    x: i32,
+    y: i32,
+    clients: &Query<
+        (
+            Entity,
+            &ClientWindow,
+            &ClientFrame,
+            &Geometry,
+            &ClientState,
+            Has<Fullscreened>,
+        ),
+        With<Client>,
+    >,
+    monitors: &mut Query<
+        (Entity, &mut Tags, &Geometry, Option<&SelectedClient>),
+        (With<Monitor>, Without<Client>),
+    >,
+    commands: &mut Commands,
+) {
+    let visible = monitors
+        .iter()
+        .flat_map(|(_, tags, ..)| {
+            tags.shown_with_sticky(|client| clients.get(client).ok().map(|(.., state, _)| state))
+        })
+        .collect::<Vec<Entity>>();
+
+    let target = clients
+        .iter()
+        .find(|(other, _, _, geometry, state, fullscreened)| {
+            *other != client
+                && visible.contains(other)
+                && !state.floating()
+                && !fullscreened
+                && geometry.contains(x, y)
+        })
+        .map(|(other, ..)| other);
+
+    let monitor_geoms = monitors
+        .iter()
+        .map(|(_, _, geometry, _)| *geometry)
+        .collect::<Vec<Geometry>>();
+    let monitor_idx = find_monitor(Geometry::new(x, y, 1, 1), monitor_geoms) as usize;
+    let Some((destination, ..)) = monitors.iter().nth(monitor_idx) else {
+        return;
+    };
+
+    let Some((source, ..)) = monitors
+        .iter()
+        .find(|(_, tags, ..)| tags.contains_client(client))
+    else {
+        return;
+    };
+
+    if source == destination {
+        let Ok((_, mut tags, ..)) = monitors.get_mut(source) else {
+            return;
+        };
+
+        // snap the client back into place, even without a target to swap with
+        tags.set_changed();
+
+        if let Some(target) = target {
+            tags.swap_clients(client, target);
+        }
+
+        return;
+    }
+
+    if let Ok((_, mut tags, _, selected)) = monitors.get_mut(source) {
+        tags.remove_client(client);
+
+        if selected.is_some_and(|selected| **selected == client) {
+            commands.entity(source).remove::<SelectedClient>();
         }
     }
+
+    if let Ok((_, mut tags, ..)) = monitors.get_mut(destination)
+        && let Some(tag) = tags.current_mut()
+    {
+        let idx = target
+            .and_then(|target| tag.clients().iter().position(|other| *other == target))
+            .unwrap_or(tag.clients().len());
+        tag.clients_mut().insert(idx, client);
+
+        commands.entity(destination).insert(SelectedClient(client));
+    }
 }
 
 pub fn handle_motion_notify(
@@ -333,25 +726,224 @@ pub fn handle_motion_notify(
     }
 }
 
+pub fn handle_resize_motion(
+    mut events: EventReader<X11Event>,
+    mut monitors: Query<(&mut Tags, &Geometry), With<Monitor>>,
+    clients: Query<(&ClientState, Has<Fullscreened>), With<Client>>,
+    resizing: Res<Resizing>,
+) {
+    // only the last motion matters, arranging for every motion event would lag behind the pointer
+    let Some(motion) = events
+        .read()
+        .filter_map(|event| match event {
+            X11Event::MotionNotify(event) => Some(event),
+            _ => None,
+        })
+        .last()
+    else {
+        return;
+    };
+
+    let Some((client, edge)) = **resizing else {
+        return;
+    };
+
+    for (mut tags, area) in &mut monitors {
+        if !tags.contains_client(client) {
+            continue;
+        }
+
+        let tiled = tags.tiled_clients(
+            |client| clients.get(client).is_ok_and(|(state, _)| state.sticky()),
+            |client| {
+                clients
+                    .get(client)
+                    .is_ok_and(|(state, fullscreened)| !state.floating() && !fullscreened)
+            },
+        );
+
+        let Some(tag) = tags.current_mut() else {
+            continue;
+        };
+
+        resize_layout(
+            tag,
+            *area,
+            &tiled,
+            client,
+            edge,
+            motion.root_x as i32,
+            motion.root_y as i32,
+        );
+    }
+}
+
+pub fn handle_resize_release(mut events: EventReader<X11Event>, mut resizing: ResMut<Resizing>) {
+    for event in events.read() {
+        if let X11Event::ButtonRelease(event) = event
+            && event.detail == 1
+            && resizing.is_some()
+        {
+            // the grab and its cursor end with the release
+            *resizing = Resizing(None);
+            debug!("stopped resizing");
+        }
+    }
+}
+
+/// The client and monitor bindings act on. Bindings only act on the focused client while it is
+/// still selected, and on its monitor. Without one, they act on the monitor under the pointer.
+fn binding_target(
+    monitors: &Query<(Entity, Option<&SelectedClient>, &Geometry), With<Monitor>>,
+    focused: &FocusedClient,
+    root_window: Window,
+    conn: &X11Connection,
+) -> (Option<Entity>, Option<Entity>) {
+    if let Some((monitor, Some(selected), _)) = monitors
+        .iter()
+        .find(|(_, selected, _)| selected.is_some_and(|selected| Some(**selected) == **focused))
+    {
+        return (Some(**selected), Some(monitor));
+    }
+
+    let QueryPointerReply { root_x, root_y, .. } =
+        conn.query_pointer(root_window).unwrap().reply().unwrap();
+
+    let monitor = monitors
+        .iter()
+        .find(|(.., geometry)| geometry.contains(root_x as i32, root_y as i32))
+        .or_else(|| monitors.iter().next())
+        .map(|(monitor, ..)| monitor);
+
+    (None, monitor)
+}
+
 pub fn handle_key_press(
     mut events: EventReader<X11Event>,
     mut keyboard_events: EventWriter<KeybindTriggered>,
-    query: Query<Entity, With<Client>>,
+    mut typed: EventWriter<KeyTyped>,
+    monitors: Query<(Entity, Option<&SelectedClient>, &Geometry), With<Monitor>>,
+    focused: Res<FocusedClient>,
+    hints: Res<Hints>,
     conn: Res<X11Connection>,
+    root_window: Res<MainRootWindow>,
 ) {
     for event in events.read() {
         if let X11Event::KeyPress(event) = event {
+            // while hint mode is active, keys choose a hint instead of triggering bindings
+            if hints.action().is_some() {
+                if let Some(name) = key_name(event.detail, &conn) {
+                    typed.write(KeyTyped(name));
+                }
+                continue;
+            }
+
             let Some(action) = find_keybind_action_for(event.detail, event.state, &conn) else {
                 continue;
             };
 
-            if query.is_empty() {
-                keyboard_events.write(KeybindTriggered::new(action.clone(), None));
+            let (client, monitor) = binding_target(&monitors, &focused, **root_window, &conn);
+            keyboard_events.write(KeybindTriggered::new(action, client, monitor));
+        }
+    }
+}
+
+/// Reports released modifiers of the `switch_client` bindings, which are only received while the
+/// keyboard is grabbed.
+pub fn handle_key_release(
+    mut events: EventReader<X11Event>,
+    mut released: EventWriter<ModifierReleased>,
+    conn: Res<X11Connection>,
+) {
+    for event in events.read() {
+        if let X11Event::KeyRelease(event) = event
+            && u16::from(modifier_key_mask(event.detail, &conn)) & u16::from(switcher_mask()) != 0
+        {
+            released.write(ModifierReleased);
+        }
+    }
+}
+
+/// Lets other programs view and rename workspaces by setting the `_RWM_VIEW_WORKSPACE` or
+/// `_RWM_RENAME_WORKSPACE` property of the root window to a label, e.g. with `xprop`.
+pub fn handle_workspace_request(
+    mut events: EventReader<X11Event>,
+    mut keyboard_events: EventWriter<KeybindTriggered>,
+    monitors: Query<(Entity, Option<&SelectedClient>, &Geometry), With<Monitor>>,
+    focused: Res<FocusedClient>,
+    conn: Res<X11Connection>,
+    root_window: Res<MainRootWindow>,
+    view_workspace: Res<RwmViewWorkspace>,
+    rename_workspace: Res<RwmRenameWorkspace>,
+) {
+    for event in events.read() {
+        let X11Event::PropertyNotify(event) = event else {
+            continue;
+        };
+
+        // reading a request deletes it, which is reported as well
+        if event.window != **root_window || event.state == Property::DELETE {
+            continue;
+        }
+
+        let action: fn(String) -> KeybindAction = if event.atom == **view_workspace {
+            KeybindAction::ViewWorkspace
+        } else if event.atom == **rename_workspace {
+            KeybindAction::RenameWorkspace
+        } else {
+            continue;
+        };
+
+        let label = match take_text_property(&conn, event.window, event.atom) {
+            Ok(label) => label.trim().to_string(),
+            Err(e) => {
+                warn!("failed reading workspace request: {e}");
+                continue;
             }
-            for client in query {
-                // TODO: focusing
-                keyboard_events.write(KeybindTriggered::new(action.clone(), Some(client)));
+        };
+
+        let (client, monitor) = binding_target(&monitors, &focused, **root_window, &conn);
+        keyboard_events.write(KeybindTriggered::new(action(label), client, monitor));
+    }
+}
+
+/// Handles `_NET_WM_STATE` requests of clients to become sticky or stop being sticky.
+pub fn handle_client_message(
+    mut events: EventReader<X11Event>,
+    mut clients: Query<(Entity, &ClientWindow, &mut ClientState), With<Client>>,
+    mut monitors: Query<&mut Tags, With<Monitor>>,
+    wm_state: Res<NetWMState>,
+    wm_state_sticky: Res<NetWMStateSticky>,
+) {
+    for event in events.read() {
+        let X11Event::ClientMessage(event) = event else {
+            continue;
+        };
+
+        if event.type_ != **wm_state {
+            continue;
+        }
+
+        let data = event.data.as_data32();
+        if data[1] != **wm_state_sticky && data[2] != **wm_state_sticky {
+            continue;
+        }
+
+        for (client, window, mut state) in &mut clients {
+            if **window != event.window {
+                continue;
             }
+
+            // _NET_WM_STATE_REMOVE, _NET_WM_STATE_ADD and _NET_WM_STATE_TOGGLE
+            let sticky = match data[0] {
+                0 => false,
+                1 => true,
+                2 => !state.sticky(),
+                _ => continue,
+            };
+
+            state.change_sticky(sticky);
+            relayout_client(client, &mut monitors);
         }
     }
 }
@@ -373,6 +965,85 @@ pub fn handle_map_notify(
     }
 }
 
+pub fn handle_property_notify(
+    mut events: EventReader<X11Event>,
+    mut query: Query<(Entity, &ClientWindow, &mut ClientName, Has<TabBar>), With<Client>>,
+    mut monitors: Query<&mut Tags, With<Monitor>>,
+    conn: Res<X11Connection>,
+) {
+    for event in events.read() {
+        if let X11Event::PropertyNotify(event) = event {
+            if event.atom != u32::from(AtomEnum::WM_NAME) {
+                continue;
+            }
+
+            for (client, window, mut name, tabbed) in &mut query {
+                if **window != event.window {
+                    continue;
+                }
+
+                match window_name(&conn, **window) {
+                    Ok(new_name) => *name = ClientName(new_name),
+                    Err(e) => warn!("failed updating name of window {}: {e}", **window),
+                }
+
+                if !tabbed {
+                    continue;
+                }
+
+                // redraw the tab bars showing this client
+                for mut tags in &mut monitors {
+                    if tags.contains_client(client) {
+                        tags.set_changed();
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn handle_expose(
+    mut events: EventReader<X11Event>,
+    query: Query<(Entity, &ClientFrame), (With<Client>, With<TabBar>)>,
+    mut monitors: Query<&mut Tags, With<Monitor>>,
+    mut switcher: ResMut<Switcher>,
+    mut hints: ResMut<Hints>,
+) {
+    for event in events.read() {
+        if let X11Event::Expose(event) = event {
+            if event.count != 0 {
+                continue;
+            }
+
+            if switcher.popup() == Some(event.window) {
+                switcher.set_changed();
+                continue;
+            }
+
+            if hints
+                .hints()
+                .iter()
+                .any(|hint| hint.popup() == event.window)
+            {
+                hints.set_changed();
+                continue;
+            }
+
+            for (client, frame) in query {
+                if **frame != event.window {
+                    continue;
+                }
+
+                for mut tags in &mut monitors {
+                    if tags.contains_client(client) {
+                        tags.set_changed();
+                    }
+                }
+            }
+        }
+    }
+}
+
 pub fn handle_error(mut events: EventReader<X11Event>) {
     for event in events.read() {
         // fuck it for now, we're only logging errors in dev
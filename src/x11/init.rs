@@ -7,10 +7,11 @@ use x11rb::{
     protocol::{
         ErrorKind,
         xproto::{
-            ButtonIndex, ChangeWindowAttributesAux, ConnectionExt, CreateGCAux, EventMask,
-            GrabMode, MapState, Screen,
+            AtomEnum, ButtonIndex, ChangeWindowAttributesAux, ConnectionExt, CreateGCAux,
+            EventMask, GrabMode, MapState, PropMode, Screen,
         },
     },
+    wrapper::ConnectionExt as _,
 };
 
 wrapper!(AvailableScreens(Vec<Screen>));
@@ -24,6 +25,7 @@ pub fn connect(mut commands: Commands) {
     commands.insert_resource(X11Connection(Arc::new(conn)));
     commands.insert_resource(ScreenNumber(screen_num));
     commands.insert_resource(Dragging(None));
+    commands.insert_resource(Resizing(None));
 }
 
 pub fn become_wm(
@@ -32,8 +34,12 @@ pub fn become_wm(
     screen_num: Res<ScreenNumber>,
 ) {
     let screen = screens[**screen_num].clone();
-    let change = ChangeWindowAttributesAux::default()
-        .event_mask(EventMask::SUBSTRUCTURE_REDIRECT | EventMask::SUBSTRUCTURE_NOTIFY);
+    let change = ChangeWindowAttributesAux::default().event_mask(
+        EventMask::SUBSTRUCTURE_REDIRECT
+            | EventMask::SUBSTRUCTURE_NOTIFY
+            | EventMask::PROPERTY_CHANGE
+            | EventMask::ENTER_WINDOW,
+    );
     let res = conn
         .change_window_attributes(screen.root, &change)
         .unwrap()
@@ -48,18 +54,25 @@ pub fn become_wm(
 pub fn load_monitors(
     screens: Res<AvailableScreens>,
     config: Res<MainConfig>,
+    layouts: Res<Layouts>,
     mut commands: Commands,
 ) {
-    let tags_cfg = config.tags().clone();
-    let tags = tags_cfg
-        .enabled_tags()
+    let tags = config
+        .tags()
+        .definitions()
         .iter()
-        .map(|tag| {
-            let label = tags_cfg.label(*tag).unwrap();
-
-            Tag::new(*tag, label, Layout::new("", "", test_layout))
+        .enumerate()
+        .map(|(idx, definition)| {
+            Tag::configured(idx as u8 + 1, definition.label().clone(), &config, &layouts)
         })
         .collect::<Vec<Tag>>();
+    let mut tags = Tags(tags);
+
+    // the first enabled tag is viewed initially
+    let tagset = tags
+        .first()
+        .map_or_else(Tagset::default, |tag| Tagset::single(tag.idx()));
+    tags.view(&tagset);
 
     let mut prev_screen = None;
 
@@ -78,9 +91,10 @@ pub fn load_monitors(
             Monitor,
             MonitorId(idx as u8),
             dimensions,
-            SelectedTagset(Tagset::default()),
-            Tagset::default(),
-            Tags(tags.clone()),
+            SelectedTagset(tagset),
+            PreviousTagset(tagset),
+            FocusStack(vec![]),
+            tags.clone(),
         ));
 
         prev_screen = Some(screen);
@@ -119,11 +133,35 @@ pub fn init(
         .atom;
     commands.insert_resource(NetWMDeleteWindow(wm_delete_window));
 
+    // atoms of requests are compared for every event, so they are only interned once
+    let atoms = intern_atoms(&conn);
+    commands.insert_resource(NetWMState(atoms.wm_state()));
+    commands.insert_resource(NetWMStateSticky(atoms.wm_state_sticky()));
+    let view_workspace = intern_atom(&conn, b"_RWM_VIEW_WORKSPACE");
+    let rename_workspace = intern_atom(&conn, b"_RWM_RENAME_WORKSPACE");
+    commands.insert_resource(RwmViewWorkspace(view_workspace));
+    commands.insert_resource(RwmRenameWorkspace(rename_workspace));
+
+    let supported = [
+        atoms.number_of_desktops(),
+        atoms.current_desktop(),
+        atoms.desktop_names(),
+        atoms.wm_state(),
+        atoms.wm_state_sticky(),
+    ];
+    conn.change_property32(
+        PropMode::REPLACE,
+        screen.root,
+        intern_atom(&conn, b"_NET_SUPPORTED"),
+        AtomEnum::ATOM,
+        &supported,
+    )?;
+
     Ok(())
 }
 
 pub fn scan_existing_windows(
-    mut monitors: Query<&mut Tags, With<Monitor>>,
+    mut monitors: Query<(&mut Tags, &Geometry), With<Monitor>>,
     mut commands: Commands,
     conn: Res<X11Connection>,
     screens: Res<AvailableScreens>,
@@ -159,9 +197,23 @@ pub fn scan_existing_windows(
                     geometry.height as u32,
                 );
 
-                for mut tags in &mut monitors {
-                    let tag = tags.get_mut(0).unwrap();
-                    manage(&conn.clone(), win, geometry, screen.root, tag, &mut commands)?;
+                let monitor_geoms = monitors
+                    .iter()
+                    .map(|(_, geometry)| *geometry)
+                    .collect::<Vec<Geometry>>();
+                let monitor = find_monitor(geometry, monitor_geoms) as usize;
+
+                if let Some((mut tags, _)) = monitors.iter_mut().nth(monitor)
+                    && let Some(tag) = tags.current_mut()
+                {
+                    manage(
+                        &conn.clone(),
+                        win,
+                        geometry,
+                        screen.root,
+                        Some(tag),
+                        &mut commands,
+                    )?;
                 }
             }
         }
@@ -185,14 +237,21 @@ pub fn grab(conn: Res<X11Connection>, root_window: Res<MainRootWindow>) {
         )
         .unwrap();
 
-    conn
-        .grab_key(
-            false,
-            **root_window,
-            mod_mask(),
-            0, /* any key */
-            GrabMode::ASYNC,
-            GrabMode::ASYNC,
-        )
-        .unwrap();
+    for keybind in config().bindings() {
+        let mask = keybind_mask(keybind);
+
+        for code in keycodes_for(keybind.key(), &conn) {
+            for lock in lock_masks() {
+                conn.grab_key(
+                    false,
+                    **root_window,
+                    mask | lock,
+                    code,
+                    GrabMode::ASYNC,
+                    GrabMode::ASYNC,
+                )
+                .unwrap();
+            }
+        }
+    }
 }
@@ -1,3 +1,5 @@
+use x11rb::connection::Connection;
+use x11rb::errors::ReplyError;
 use x11rb::protocol::xproto::{ConnectionExt, KeyButMask, Keycode, ModMask};
 
 use crate::prelude::*;
@@ -14,31 +16,64 @@ pub fn mod_mask() -> ModMask {
     }
 }
 
-fn decompose(mask: KeyButMask) -> Vec<KeyButMask> {
-    let variants = [
-        KeyButMask::SHIFT,
-        KeyButMask::LOCK,
-        KeyButMask::CONTROL,
-        KeyButMask::MOD1,
-        KeyButMask::MOD2,
-        KeyButMask::MOD3,
-        KeyButMask::MOD4,
-        KeyButMask::MOD5,
-        KeyButMask::BUTTON1,
-        KeyButMask::BUTTON2,
-        KeyButMask::BUTTON3,
-        KeyButMask::BUTTON4,
-        KeyButMask::BUTTON5,
-    ];
-
-    let mut result = Vec::new();
-    for variant in variants.iter() {
-        if mask.bits() & variant.bits() != 0 {
-            result.push(*variant);
-        }
+/// The combinations of the lock modifiers, i.e. caps lock and num lock, which must not keep
+/// bindings from matching.
+pub fn lock_masks() -> [ModMask; 4] {
+    [
+        ModMask::from(0u16),
+        ModMask::LOCK,
+        ModMask::M2,
+        ModMask::LOCK | ModMask::M2,
+    ]
+}
+
+/// The mask of the given [`modifier`].
+fn modifier_mask(modifier: &str) -> ModMask {
+    match modifier {
+        "shift" => ModMask::SHIFT,
+        "control" | "ctrl" => ModMask::CONTROL,
+        "alt" => ModMask::M1,
+        "super" | "meta" | "windows" | "win" => ModMask::M4,
+
+        _ => unreachable!(), // config should already have been validated
     }
+}
+
+/// The combined mask of the modifiers of the given [`keybind`], which defaults to the mod key.
+pub fn keybind_mask(keybind: &KeyBinding) -> ModMask {
+    let modifiers = keybind
+        .modifiers()
+        .clone()
+        .unwrap_or_else(|| vec![config().keyboard().mod_key().clone()]);
+
+    modifiers
+        .iter()
+        .fold(ModMask::from(0u16), |mask, modifier| {
+            mask | modifier_mask(modifier)
+        })
+}
+
+/// All keycodes that produce the given [`key`] as it is used in bindings.
+pub fn keycodes_for(key: &str, conn: &X11Connection) -> Vec<Keycode> {
+    let (min, max) = (conn.setup().min_keycode, conn.setup().max_keycode);
+    let Ok(reply) = conn
+        .get_keyboard_mapping(min, max - min + 1)
+        .map_err(ReplyError::from)
+        .and_then(|cookie| cookie.reply())
+    else {
+        return vec![];
+    };
 
-    result
+    let per_keycode = reply.keysyms_per_keycode.max(1) as usize;
+    (min..=max)
+        .filter(|code| {
+            reply
+                .keysyms
+                .get((code - min) as usize * per_keycode)
+                .and_then(|keysym| decode_keysym(*keysym, *code))
+                .is_some_and(|name| name == key)
+        })
+        .collect()
 }
 
 pub fn find_keybind_action_for(
@@ -55,29 +90,16 @@ pub fn find_keybind_action_for(
         }
     };
 
-    let pressed_mods = decompose(mask);
-    info!("pressed {keysym} with mods {mask:?}: {pressed_mods:?}");
+    // lock modifiers and pressed buttons are ignored
+    let relevant = ModMask::SHIFT | ModMask::CONTROL | ModMask::M1 | ModMask::M4;
+    let pressed = u16::from(mask) & u16::from(relevant);
+    info!("pressed {keysym} with mods {mask:?}");
 
     for keybind in config().bindings() {
-        let mods = keybind
-            .modifiers()
-            .clone()
-            .unwrap_or_else(|| vec![config().keyboard().mod_key().clone()])
-            .into_iter()
-            .map(|modifier| match &*modifier {
-                "shift" => KeyButMask::SHIFT,
-                "control" | "ctrl" => KeyButMask::CONTROL,
-                "alt" => KeyButMask::MOD1,
-                "super" | "meta" | "windows" | "win" => KeyButMask::MOD4,
-
-                _ => unreachable!(), // config should already have been validated
-            })
-            .collect::<Vec<KeyButMask>>();
-
-        if mods != pressed_mods {
+        if u16::from(keybind_mask(keybind)) != pressed {
             continue;
         }
-        
+
         if keybind.key() == &keysym {
             return Some(keybind.action().clone());
         }
@@ -101,10 +123,56 @@ fn find_keysym(code: Keycode, conn: &X11Connection) -> Result<String> {
     }
 }
 
+/// The name of the key with the given [`code`] as it is used in bindings, e.g. `a` or `escape`.
+pub fn key_name(code: Keycode, conn: &X11Connection) -> Option<String> {
+    find_keysym(code, conn).ok()
+}
+
+/// The mask of the modifier the key with the given [`code`] belongs to, e.g. [`ModMask::M4`] for
+/// `Super_L`. Keys that are no modifiers have an empty mask.
+pub fn modifier_key_mask(code: Keycode, conn: &X11Connection) -> ModMask {
+    let Ok(reply) = conn
+        .get_keyboard_mapping(code, 1)
+        .map_err(ReplyError::from)
+        .and_then(|cookie| cookie.reply())
+    else {
+        return ModMask::from(0u16);
+    };
+
+    match reply.keysyms.first() {
+        Some(0xffe1 | 0xffe2) => ModMask::SHIFT, // Shift_L, Shift_R
+        Some(0xffe3 | 0xffe4) => ModMask::CONTROL, // Control_L, Control_R
+        Some(0xffe7..=0xffea) => ModMask::M1,    // Meta_L to Alt_R
+        Some(0xffeb..=0xffee) => ModMask::M4,    // Super_L to Hyper_R
+
+        _ => ModMask::from(0u16),
+    }
+}
+
+/// The modifiers shared by all `switch_client` bindings, which have to be held to keep the
+/// window switcher open.
+pub fn switcher_mask() -> ModMask {
+    config()
+        .bindings()
+        .iter()
+        .filter(|keybind| matches!(keybind.action(), KeybindAction::SwitchClient(_)))
+        .map(|keybind| u16::from(keybind_mask(keybind)))
+        .reduce(|shared, mask| shared & mask)
+        .map_or(ModMask::from(0u16), ModMask::from)
+}
+
 fn decode_keysym(keysym: u32, keycode: Keycode) -> Option<String> {
     if keycode == 36 {
         return Some("return".to_string())
     }
+
+    if keysym == 0xff09 {
+        return Some("tab".to_string());
+    }
+
+    if keysym == 0xff1b {
+        return Some("escape".to_string());
+    }
  
     if (0x20..=0x7E).contains(&keysym) {
         Some((keysym as u8 as char).to_string())
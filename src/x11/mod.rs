@@ -3,10 +3,14 @@ use std::sync::Mutex;
 use std::{cmp::Reverse, collections::BinaryHeap, sync::Arc};
 use x11rb::{
     connection::Connection,
-    protocol::xproto::{AtomEnum, ConnectionExt, CreateWindowAux, EventMask, SetMode, WindowClass},
+    properties::{WmClass, WmSizeHints},
+    protocol::xproto::{
+        AtomEnum, ChangeWindowAttributesAux, ClientMessageEvent, ConnectionExt, CreateWindowAux,
+        EventMask, PropMode, SetMode, WindowClass,
+    },
     rust_connection::RustConnection,
     wrapper::ConnectionExt as _,
-    COPY_DEPTH_FROM_PARENT,
+    COPY_DEPTH_FROM_PARENT, CURRENT_TIME,
 };
 
 pub mod atom;
@@ -18,9 +22,21 @@ pub mod platform;
 wrapper!(X11Connection(Arc<RustConnection>));
 wrapper!(MainRootWindow(Window));
 wrapper!(Dragging(Option<(Window, i16, i16)>));
+wrapper!(Resizing(Option<(Entity, Direction)>));
+
+/// How far outside of the border of a tiled client dragging starts resizing the layout.
+pub const RESIZE_MARGIN: u32 = 8;
 
 static IGNORED_SEQUENCES: Mutex<BinaryHeap<Reverse<u16>>> = Mutex::new(BinaryHeap::new());
 
+/// The sequence number of the first request after the layout last moved clients. Pointer crossings
+/// caused by the move are reported with an earlier sequence number and must not change focus.
+static LAYOUT_SEQUENCE: Mutex<Option<u16>> = Mutex::new(None);
+
+pub fn intern_atom(conn: &X11Connection, name: &[u8]) -> u32 {
+    conn.intern_atom(false, name).unwrap().reply().unwrap().atom
+}
+
 pub fn window_name(conn: &X11Connection, window: Window) -> Result<String> {
     let reply = conn
         .get_property(
@@ -38,17 +54,170 @@ pub fn window_name(conn: &X11Connection, window: Window) -> Result<String> {
     Ok(String::from_utf8(reply.value)?)
 }
 
+/// The WM_CLASS instance and class names of the given [`window`].
+pub fn window_class(conn: &X11Connection, window: Window) -> Result<Vec<String>> {
+    let Some(class) = WmClass::get(&***conn, window)
+        .context("failed getting WM_CLASS")?
+        .reply()
+        .context("failed receiving WM_CLASS")?
+    else {
+        return Ok(vec![]);
+    };
+
+    Ok(vec![
+        String::from_utf8(class.instance().to_vec())?,
+        String::from_utf8(class.class().to_vec())?,
+    ])
+}
+
+/// The `_NET_WM_STATE` atoms of the given [`window`].
+pub fn window_states(conn: &X11Connection, window: Window) -> Result<Vec<u32>> {
+    let reply = conn
+        .get_property(
+            false,
+            window,
+            atoms().wm_state(),
+            AtomEnum::ATOM,
+            0,
+            u32::MAX,
+        )
+        .context("failed getting _NET_WM_STATE")?
+        .reply()
+        .context("failed receiving _NET_WM_STATE")?;
+
+    Ok(reply
+        .value32()
+        .map(|states| states.collect())
+        .unwrap_or_default())
+}
+
+/// Adds the given [`state`] atom to the `_NET_WM_STATE` of the given [`window`] if it is
+/// [`enabled`], removes it otherwise.
+pub fn set_window_state(
+    conn: &X11Connection,
+    window: Window,
+    state: u32,
+    enabled: bool,
+) -> Result<()> {
+    let mut states = window_states(conn, window)?;
+    states.retain(|other| *other != state);
+
+    if enabled {
+        states.push(state);
+    }
+
+    conn.change_property32(
+        PropMode::REPLACE,
+        window,
+        atoms().wm_state(),
+        AtomEnum::ATOM,
+        &states,
+    )?;
+
+    Ok(())
+}
+
+/// Asks the given [`window`] to close itself if it supports `WM_DELETE_WINDOW`, kills its client
+/// otherwise.
+pub fn close_window(conn: &X11Connection, window: Window) -> Result<()> {
+    let wm_protocols = intern_atom(conn, b"WM_PROTOCOLS");
+    let wm_delete_window = intern_atom(conn, b"WM_DELETE_WINDOW");
+
+    let reply = conn
+        .get_property(false, window, wm_protocols, AtomEnum::ATOM, 0, u32::MAX)
+        .context("failed getting WM_PROTOCOLS")?
+        .reply()
+        .context("failed receiving WM_PROTOCOLS")?;
+
+    let supported = reply
+        .value32()
+        .is_some_and(|mut protocols| protocols.any(|protocol| protocol == wm_delete_window));
+    if !supported {
+        conn.kill_client(window)?;
+        return Ok(());
+    }
+
+    let event = ClientMessageEvent::new(
+        32,
+        window,
+        wm_protocols,
+        [wm_delete_window, CURRENT_TIME, 0, 0, 0],
+    );
+    conn.send_event(false, window, EventMask::NO_EVENT, event)?;
+
+    Ok(())
+}
+
+/// Reads and deletes the given text [`property`] of the given [`window`].
+pub fn take_text_property(conn: &X11Connection, window: Window, property: u32) -> Result<String> {
+    let reply = conn
+        .get_property(true, window, property, AtomEnum::ANY, 0, u32::MAX)
+        .context("failed getting text property")?
+        .reply()
+        .context("failed receiving text property")?;
+
+    Ok(String::from_utf8(reply.value)?)
+}
+
+pub fn size_hints(conn: &X11Connection, window: Window) -> Result<SizeHints> {
+    let Some(hints) = WmSizeHints::get_normal_hints(&***conn, window)
+        .context("failed getting WM_NORMAL_HINTS")?
+        .reply()
+        .context("failed receiving WM_NORMAL_HINTS")?
+    else {
+        return Ok(SizeHints::default());
+    };
+
+    let dimension = |size: Option<(i32, i32)>| {
+        size.map_or_else(SizeDimensionHint::default, |(width, height)| {
+            SizeDimensionHint::new(width.max(0) as u32, height.max(0) as u32)
+        })
+    };
+
+    let aspect_ratio = hints
+        .aspect
+        .map_or_else(SizeConstraintHint::default, |(min, max)| {
+            SizeConstraintHint::new(
+                min.numerator as f32 / min.denominator.max(1) as f32,
+                max.numerator as f32 / max.denominator.max(1) as f32,
+            )
+        });
+
+    Ok(SizeHints::new(
+        dimension(hints.base_size),
+        dimension(hints.size_increment),
+        dimension(hints.max_size),
+        dimension(hints.min_size),
+        aspect_ratio,
+    ))
+}
+
 pub fn manage(
     conn: &X11Connection,
     window: Window,
     geometry: Geometry,
     root_window: Window,
-    tag: &mut Tag,
+    tag: Option<&mut Tag>,
     commands: &mut Commands,
 ) -> Result<(Entity, ClientFrame)> {
     info!("Managing window {window} (geom: {geometry:#?}");
 
     let window_name = window_name(conn, window)?.to_string();
+    let size_hints = size_hints(conn, window)?;
+    let classes = window_class(conn, window)?;
+
+    let mut state = ClientState::default();
+    if window_states(conn, window)?.contains(&atoms().wm_state_sticky()) {
+        state.change_sticky(true);
+    }
+
+    for rule in config()
+        .rules()
+        .iter()
+        .filter(|rule| rule.matches(&classes, &window_name))
+    {
+        rule.apply(&mut state);
+    }
 
     let frame_window = conn.generate_id()?;
     let config = config();
@@ -93,6 +262,10 @@ pub fn manage(
         (border_width as f32 * 1.5) as i16,
         (border_width as f32 * 1.5) as i16,
     )?;
+    conn.change_window_attributes(
+        window,
+        &ChangeWindowAttributesAux::new().event_mask(EventMask::PROPERTY_CHANGE),
+    )?;
     conn.map_window(window)?;
     conn.map_window(frame_window)?;
     conn.ungrab_server()?;
@@ -106,13 +279,19 @@ pub fn manage(
             OriginalGeometry(geometry),
             ClientWindow(window),
             ClientFrame(frame_window),
-            ClientState::default(),
+            state,
+            ClientWeight(DEFAULT_CLIENT_WEIGHT),
+            size_hints,
         ))
         .id();
 
-    tag.clients_mut().push(client);
-
-    info!("Managed window {window_name} on tag {}", tag.idx());
+    match tag {
+        Some(tag) => {
+            tag.clients_mut().push(client);
+            info!("Managed window {window_name} on tag {}", tag.idx());
+        }
+        None => info!("Managed window {window_name} outside of any tag"),
+    }
 
     IGNORED_SEQUENCES
         .lock()
@@ -130,10 +309,10 @@ pub fn unmanage(
     frame: Option<Window>,
     root_window: Window,
     commands: &mut Commands,
-    tag: &mut Tag,
+    tags: &mut Tags,
 ) {
     trace!("unmanaging window {window}");
-    tag.clients_mut().retain(|c| &client != c);
+    tags.remove_client(client);
 
     if let Ok(mut entity) = commands.get_entity(client) {
         entity.despawn();
@@ -1,5 +1,8 @@
 use crate::prelude::*;
-use x11rb::protocol::xproto::InputFocus;
+use x11rb::protocol::xproto::{
+    AtomEnum, ButtonIndex, ChangeGCAux, CreateGCAux, GrabMode, GrabStatus, InputFocus, ModMask,
+    PropMode, Rectangle, StackMode,
+};
 use x11rb::{
     connection::Connection, protocol::xproto::{
         ConfigureWindowAux, ConnectionExt, CreateWindowAux, EventMask, WindowClass,
@@ -23,7 +26,7 @@ impl RWMPlatform for X11 {
         commands: &mut Commands,
         conn: &Self::Connection,
     ) -> Result<(Entity, ClientFrame)> {
-        manage(conn, window, geometry, root_window, tag, commands)
+        manage(conn, window, geometry, root_window, Some(tag), commands)
     }
 
     fn unmanage(
@@ -32,7 +35,7 @@ impl RWMPlatform for X11 {
         geometry: Geometry,
         frame: Option<Window>,
         root_window: Window,
-        tag: &mut Tag,
+        tags: &mut Tags,
         commands: &mut Commands,
         conn: &Self::Connection,
     ) {
@@ -44,7 +47,7 @@ impl RWMPlatform for X11 {
             frame,
             root_window,
             commands,
-            tag,
+            tags,
         )
     }
 
@@ -55,31 +58,58 @@ impl RWMPlatform for X11 {
         frame: Window,
         conn: &Self::Connection,
     ) {
-        let border_width = config.border().width() as i16;
+        Self::update_tabbed_client_geometry(config, geometry, 0, window, frame, conn);
+    }
+
+    fn update_tabbed_client_geometry(
+        config: &MainConfig,
+        geometry: Geometry,
+        tab_height: u32,
+        window: Window,
+        frame: Window,
+        conn: &Self::Connection,
+    ) {
+        let border_width = config.border().width() as u32;
+        let offset = (border_width as f32 * 1.5) as i32;
 
         conn.configure_window(
-            window,
+            frame,
             &ConfigureWindowAux::new()
-                // what the fuck
-                .x(((geometry.x() as i16) - border_width) as i32)
-                .y(((geometry.y() as i16) - border_width) as i32)
-                .width(((geometry.width() as i16) + 2 * border_width) as u32)
-                .height(((geometry.height() as i16) + 2 * border_width) as u32),
+                .x(geometry.x() - border_width as i32)
+                .y(geometry.y() - border_width as i32 - tab_height as i32)
+                .width(geometry.width() + 2 * border_width)
+                .height(geometry.height() + 2 * border_width + tab_height),
         )
         .unwrap();
 
-        conn.reparent_window(
+        // the client was already reparented into the frame when managing it, reparenting it again
+        // would unmap it and get it unmanaged
+        conn.configure_window(
             window,
-            frame,
-            // ??? why the fuck X11
-            (border_width as f32 * 1.5) as i16,
-            (border_width as f32 * 1.5) as i16,
+            &ConfigureWindowAux::new()
+                .x(offset)
+                .y(offset + tab_height as i32)
+                .width(geometry.width())
+                .height(geometry.height()),
         )
         .unwrap();
 
         conn.sync().unwrap();
     }
 
+    fn draw_tabs(
+        config: &MainConfig,
+        frame: Window,
+        width: u32,
+        tabs: &[(String, ColorScheme)],
+        style: TabStyle,
+        conn: &Self::Connection,
+    ) {
+        if let Err(e) = draw_tabs(conn, config, frame, width, tabs, style) {
+            error!("failed drawing tabs of frame {frame}: {e}");
+        }
+    }
+
     fn delete_frame(
         geometry: Geometry,
         window: Window,
@@ -147,6 +177,14 @@ impl RWMPlatform for X11 {
         Ok(ClientFrame(frame))
     }
 
+    fn move_offscreen(geometry: Geometry, window: Window, conn: &Self::Connection) {
+        conn.configure_window(
+            window,
+            &ConfigureWindowAux::new().x(geometry.offscreen().x()),
+        )
+        .unwrap();
+    }
+
     fn update_client_geometry(geometry: Geometry, window: Window, conn: &Self::Connection) {
         conn.configure_window(
             window,
@@ -164,6 +202,12 @@ impl RWMPlatform for X11 {
         conn.sync().unwrap();
     }
 
+    fn set_grab_cursor(cursor: Cursor, conn: &Self::Connection) {
+        if let Err(e) = set_grab_cursor(conn, cursor) {
+            error!("failed changing cursor to {cursor:?}: {e}");
+        }
+    }
+
     fn ungrab_mouse(conn: &Self::Connection) {
         conn.ungrab_pointer(CURRENT_TIME).unwrap();
     }
@@ -172,6 +216,279 @@ impl RWMPlatform for X11 {
         conn.set_input_focus(InputFocus::PARENT, window, CURRENT_TIME)
             .unwrap();
     }
+
+    fn raise(frame: Window, conn: &Self::Connection) {
+        conn.configure_window(
+            frame,
+            &ConfigureWindowAux::new().stack_mode(StackMode::ABOVE),
+        )
+        .unwrap();
+    }
+
+    fn grab_focus_clicks(window: Window, grab: bool, conn: &Self::Connection) {
+        let result = if grab {
+            conn.grab_button(
+                false,
+                window,
+                EventMask::BUTTON_PRESS,
+                GrabMode::SYNC,
+                GrabMode::ASYNC,
+                x11rb::NONE,
+                x11rb::NONE,
+                ButtonIndex::ANY,
+                ModMask::ANY,
+            )
+        } else {
+            conn.ungrab_button(ButtonIndex::ANY, window, ModMask::ANY)
+        };
+
+        if let Err(e) = result {
+            warn!("failed updating the click grab of window {window}: {e}");
+        }
+    }
+
+    fn close(window: Window, conn: &Self::Connection) {
+        if let Err(e) = close_window(conn, window) {
+            error!("failed closing window {window}: {e}");
+        }
+    }
+
+    fn create_popup(
+        geometry: Geometry,
+        root_window: Window,
+        conn: &Self::Connection,
+    ) -> Result<Window> {
+        let popup = conn.generate_id()?;
+
+        // popups are not managed, so they are never reparented into a frame
+        let win_aux = CreateWindowAux::new()
+            .override_redirect(1)
+            .event_mask(EventMask::EXPOSURE);
+
+        conn.create_window(
+            COPY_DEPTH_FROM_PARENT,
+            popup,
+            root_window,
+            geometry.x() as i16,
+            geometry.y() as i16,
+            geometry.width() as u16,
+            geometry.height() as u16,
+            0,
+            WindowClass::INPUT_OUTPUT,
+            0,
+            &win_aux,
+        )?;
+
+        conn.map_window(popup)?;
+
+        Ok(popup)
+    }
+
+    fn destroy_popup(popup: Window, conn: &Self::Connection) {
+        conn.destroy_window(popup).unwrap();
+    }
+
+    fn grab_keyboard(root_window: Window, conn: &Self::Connection) {
+        let status = conn
+            .grab_keyboard(
+                false,
+                root_window,
+                CURRENT_TIME,
+                GrabMode::ASYNC,
+                GrabMode::ASYNC,
+            )
+            .unwrap()
+            .reply()
+            .unwrap()
+            .status;
+
+        if status != GrabStatus::SUCCESS {
+            warn!("failed grabbing the keyboard: {status:?}");
+        }
+    }
+
+    fn ungrab_keyboard(conn: &Self::Connection) {
+        conn.ungrab_keyboard(CURRENT_TIME).unwrap();
+    }
+
+    fn modifiers_held(root_window: Window, conn: &Self::Connection) -> bool {
+        let mask = conn
+            .query_pointer(root_window)
+            .unwrap()
+            .reply()
+            .unwrap()
+            .mask;
+
+        let modifiers = u16::from(switcher_mask());
+        u16::from(mask) & modifiers == modifiers
+    }
+
+    fn set_layout_symbol(symbol: &str, root_window: Window, conn: &Self::Connection) {
+        conn.change_property8(
+            PropMode::REPLACE,
+            root_window,
+            atoms().layout_symbol(),
+            atoms().utf8_string(),
+            symbol.as_bytes(),
+        )
+        .unwrap();
+    }
+
+    fn set_sticky(window: Window, sticky: bool, conn: &Self::Connection) {
+        if let Err(e) = set_window_state(conn, window, atoms().wm_state_sticky(), sticky) {
+            warn!("failed publishing sticky state of window {window}: {e}");
+        }
+    }
+
+    fn set_desktops(
+        names: &[String],
+        current: usize,
+        root_window: Window,
+        conn: &Self::Connection,
+    ) {
+        let atoms = atoms();
+
+        // the names are a list of null-terminated strings
+        let name_list = names
+            .iter()
+            .flat_map(|name| name.bytes().chain([0]))
+            .collect::<Vec<u8>>();
+
+        conn.change_property32(
+            PropMode::REPLACE,
+            root_window,
+            atoms.number_of_desktops(),
+            AtomEnum::CARDINAL,
+            &[names.len() as u32],
+        )
+        .unwrap();
+
+        conn.change_property32(
+            PropMode::REPLACE,
+            root_window,
+            atoms.current_desktop(),
+            AtomEnum::CARDINAL,
+            &[current as u32],
+        )
+        .unwrap();
+
+        conn.change_property8(
+            PropMode::REPLACE,
+            root_window,
+            atoms.desktop_names(),
+            atoms.utf8_string(),
+            &name_list,
+        )
+        .unwrap();
+    }
+}
+
+fn set_grab_cursor(conn: &X11Connection, cursor: Cursor) -> Result<()> {
+    // glyphs of the standard X cursor font
+    let glyph = match cursor {
+        Cursor::Normal => 68,
+        Cursor::Resize => 120,
+        Cursor::Move => 52,
+        Cursor::Pencil => 86,
+    };
+
+    let font = conn.generate_id()?;
+    conn.open_font(font, b"cursor")?;
+
+    let cursor = conn.generate_id()?;
+    conn.create_glyph_cursor(
+        cursor,
+        font,
+        font,
+        glyph,
+        glyph + 1,
+        0,
+        0,
+        0,
+        u16::MAX,
+        u16::MAX,
+        u16::MAX,
+    )?;
+
+    conn.change_active_pointer_grab(
+        cursor,
+        CURRENT_TIME,
+        EventMask::BUTTON_PRESS | EventMask::BUTTON_RELEASE | EventMask::BUTTON1_MOTION,
+    )?;
+
+    conn.free_cursor(cursor)?;
+    conn.close_font(font)?;
+
+    Ok(())
+}
+
+fn draw_tabs(
+    conn: &X11Connection,
+    config: &MainConfig,
+    frame: Window,
+    width: u32,
+    tabs: &[(String, ColorScheme)],
+    style: TabStyle,
+) -> Result<()> {
+    let tab_height = config.tabs().height();
+    let text_color = config.tabs().text_color().hex_value()?;
+
+    let font = conn.generate_id()?;
+    conn.open_font(font, config.tabs().font().as_bytes())?;
+    let ascent = conn.query_font(font)?.reply()?.font_ascent;
+
+    let gc = conn.generate_id()?;
+    conn.create_gc(gc, frame, &CreateGCAux::new().font(font))?;
+
+    for (idx, (name, scheme)) in tabs.iter().enumerate() {
+        let tab = match style {
+            TabStyle::Tabbed => {
+                Geometry::new(0, 0, width, tab_height as u32).split_columns(tabs.len(), 0)[idx]
+            }
+            TabStyle::Stacked => Geometry::new(
+                0,
+                (idx * tab_height as usize) as i32,
+                width,
+                tab_height as u32,
+            ),
+        };
+
+        let color = config.border().color(*scheme)?;
+        conn.change_gc(gc, &ChangeGCAux::new().foreground(color))?;
+        conn.poly_fill_rectangle(
+            frame,
+            gc,
+            &[Rectangle {
+                x: tab.x() as i16,
+                y: tab.y() as i16,
+                width: tab.width() as u16,
+                height: tab.height() as u16,
+            }],
+        )?;
+
+        // image_text8 cannot draw more than 255 bytes
+        let mut end = name.len().min(255);
+        while !name.is_char_boundary(end) {
+            end -= 1;
+        }
+
+        conn.change_gc(
+            gc,
+            &ChangeGCAux::new().foreground(text_color).background(color),
+        )?;
+        conn.image_text8(
+            frame,
+            gc,
+            tab.x() as i16 + 4,
+            tab.y() as i16 + (tab_height as i16 + ascent) / 2,
+            &name.as_bytes()[..end],
+        )?;
+    }
+
+    conn.free_gc(gc)?;
+    conn.close_font(font)?;
+
+    Ok(())
 }
 
 impl Plugin for X11 {
@@ -190,6 +507,7 @@ impl Plugin for X11 {
                     .chain(),
             )
             .add_systems(First, poll_events)
+            .add_systems(PostUpdate, mark_layout_crossings.after(arrange))
             .add_systems(
                 Update,
                 (
@@ -197,10 +515,19 @@ impl Plugin for X11 {
                     handle_unmap_notify,
                     handle_enter_notify,
                     handle_motion_notify,
+                    handle_resize_motion,
+                    handle_focus_click,
                     handle_button_press,
                     handle_button_release,
+                    handle_resize_release,
+                    handle_tab_click,
                     handle_key_press,
+                    handle_key_release,
                     handle_map_notify,
+                    handle_property_notify,
+                    handle_workspace_request,
+                    handle_client_message,
+                    handle_expose,
                     handle_error,
                     flush,
                 )